@@ -0,0 +1,97 @@
+use std::{fmt, str::FromStr};
+
+/// A parse failure that points at the offending text in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub source_line: String,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic for `token` on `line`. `token` is expected to be a
+    /// slice of `line`; otherwise its first occurrence is used.
+    pub fn new(message: impl Into<String>, line_number: usize, line: &str, token: &str) -> Self {
+        let start = line.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let offset = if token_start >= start && token_start + token.len() <= start + line.len() {
+            token_start - start
+        } else {
+            line.find(token).unwrap_or(0)
+        };
+
+        Diagnostic {
+            message: message.into(),
+            line: line_number,
+            column: line[..offset].chars().count() + 1,
+            token: token.to_string(),
+            source_line: line.to_string(),
+        }
+    }
+
+    /// Creates a diagnostic for the character at `column` (0-based, in
+    /// characters) of `line`.
+    pub fn at_char(
+        message: impl Into<String>,
+        line_number: usize,
+        line: &str,
+        column: usize,
+    ) -> Self {
+        let token = line
+            .chars()
+            .nth(column)
+            .map(|c| c.to_string())
+            .unwrap_or_default();
+
+        Diagnostic {
+            message: message.into(),
+            line: line_number,
+            column: column + 1,
+            token,
+            source_line: line.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        let underline = "^".repeat(self.token.chars().count().max(1));
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{:gutter$} | {:indent$}{}",
+            "",
+            "",
+            underline,
+            indent = self.column - 1
+        )
+    }
+}
+
+/// Parses `token`, a slice of `line`, reporting failures against its position.
+pub fn parse_token<T>(line_number: usize, line: &str, token: &str) -> Result<T, Diagnostic>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token.parse::<T>().map_err(|e| {
+        Diagnostic::new(
+            format!("invalid number '{}': {}", token, e),
+            line_number,
+            line,
+            token,
+        )
+    })
+}
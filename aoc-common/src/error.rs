@@ -0,0 +1,55 @@
+use std::fmt;
+
+use crate::Diagnostic;
+
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Error {
+    pub fn new(message: String) -> Self {
+        Error {
+            message,
+            diagnostic: None,
+        }
+    }
+
+    /// The location in the input that caused this error, if it came from parsing.
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        self.diagnostic.as_ref()
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.diagnostic {
+            Some(diagnostic) => write!(f, "{}", diagnostic),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(value: Diagnostic) -> Self {
+        Error {
+            message: value.message.clone(),
+            diagnostic: Some(value),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::new(value.to_string())
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(value: std::num::ParseIntError) -> Self {
+        Error::new(value.to_string())
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A signed position on the plane, with `y` growing downwards to match grids.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        (other - self).chebyshev()
+    }
+
+    /// The `(x, y)` grid index of this point, or `None` if either coordinate
+    /// is negative. Upper bounds are left to the grid.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The difference between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vector { x, y }
+    }
+
+    /// Length when only moving along the axes.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Length when diagonal moves count as one step.
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    /// The shortest whole-number step in the same direction, so that adding
+    /// it repeatedly lands on every grid point along the line.
+    pub fn reduced(self) -> Vector {
+        let (mut a, mut b) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        if a == 0 {
+            return self;
+        }
+
+        Vector::new(self.x / a as isize, self.y / a as isize)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Vector {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// A compass direction. `CARDINAL` holds the four orthogonal ones and `ALL`
+/// adds the diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// One step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::NorthEast => Vector::new(1, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::SouthEast => Vector::new(1, 1),
+            Direction::South => Vector::new(0, 1),
+            Direction::SouthWest => Vector::new(-1, 1),
+            Direction::West => Vector::new(-1, 0),
+            Direction::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn clockwise(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn counter_clockwise(self) -> Self {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn turn(self, eighths: usize) -> Self {
+        // Variants are declared in clockwise order, matching `ALL`
+        Direction::ALL[(self as usize + eighths) % Direction::ALL.len()]
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self {
+            Direction::North => "North",
+            Direction::NorthEast => "NorthEast",
+            Direction::East => "East",
+            Direction::SouthEast => "SouthEast",
+            Direction::South => "South",
+            Direction::SouthWest => "SouthWest",
+            Direction::West => "West",
+            Direction::NorthWest => "NorthWest",
+        };
+
+        f.pad(direction)
+    }
+}
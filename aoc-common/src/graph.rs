@@ -0,0 +1,569 @@
+//! Searches over graphs given either as a successor function (implicit graphs,
+//! such as moves on a grid) or as an explicit adjacency list in `Graph`.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A directed graph stored as an adjacency list.
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    edges: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph {
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    /// Adds an edge from `from` to `to`, adding either node if it is new.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_node(to.clone());
+        self.edges.entry(from).or_default().push(to);
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        self.successors(from).contains(to)
+    }
+
+    pub fn successors(&self, node: &N) -> &[N] {
+        self.edges.get(node).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The nodes of a cycle found while sorting, in edge order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N> {
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search from `start`, returning the number of steps to every
+/// reachable node.
+pub fn bfs<N, F, I>(start: N, mut successors: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// The shortest path, counted in edges, from `start` to the first node
+/// matching `is_goal`, including both ends.
+pub fn bfs_path<N, F, I, G>(start: N, mut successors: F, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth-first search from `start`, returning every reachable node in the
+/// order it was first visited.
+pub fn dfs<N, F, I>(start: N, mut successors: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Vec::new();
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+
+        let mut next: Vec<N> = successors(&node).into_iter().collect();
+        // Reverse so the first successor is explored first
+        next.reverse();
+        stack.extend(next);
+        visited.push(node);
+    }
+
+    visited
+}
+
+/// The cheapest path from `start` to a node matching `is_goal`, where
+/// `successors` yields each neighbour with the cost of moving to it.
+pub fn dijkstra<N, C, F, I, G>(start: N, successors: F, is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but guided by `heuristic`, an estimate of the remaining
+/// cost that must never overestimate it.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    // Nodes are numbered as they are found so the heap doesn't need `N: Ord`
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut costs = vec![C::default()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&nodes[0]), C::default(), 0)));
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        if cost > costs[id] {
+            // A cheaper way here was already expanded
+            continue;
+        }
+
+        if is_goal(&nodes[id]) {
+            let mut path = vec![nodes[id].clone()];
+            let mut current = id;
+            while let Some(parent) = parents[current] {
+                path.push(nodes[parent].clone());
+                current = parent;
+            }
+            path.reverse();
+
+            return Some((path, cost));
+        }
+
+        for (next, step) in successors(&nodes[id]) {
+            let next_cost = cost + step;
+            let next_id = match ids.get(&next) {
+                Some(&next_id) if costs[next_id] <= next_cost => continue,
+                Some(&next_id) => {
+                    costs[next_id] = next_cost;
+                    parents[next_id] = Some(id);
+                    next_id
+                }
+                None => {
+                    let next_id = nodes.len();
+                    ids.insert(next.clone(), next_id);
+                    nodes.push(next);
+                    costs.push(next_cost);
+                    parents.push(Some(id));
+                    next_id
+                }
+            };
+
+            let estimate = next_cost + heuristic(&nodes[next_id]);
+            heap.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+/// Orders every node reachable from `roots` so that each comes before all of
+/// its successors, or returns a cycle if there is no such order.
+pub fn topological_sort<N, R, F, I>(roots: R, mut successors: F) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    R: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut finished = HashSet::new();
+    let mut order = Vec::new();
+
+    for root in roots {
+        if finished.contains(&root) {
+            continue;
+        }
+
+        // Nodes on the current path, each with the successors left to visit
+        let mut on_path = HashSet::from([root.clone()]);
+        let mut stack = vec![(root.clone(), successors(&root).into_iter())];
+        while let Some((node, remaining)) = stack.last_mut() {
+            let Some(next) = remaining.next() else {
+                let node = node.clone();
+                stack.pop();
+                on_path.remove(&node);
+                finished.insert(node.clone());
+                order.push(node);
+                continue;
+            };
+
+            if on_path.contains(&next) {
+                let start = stack
+                    .iter()
+                    .position(|(node, _)| *node == next)
+                    .unwrap_or_default();
+                let nodes = stack[start..]
+                    .iter()
+                    .map(|(node, _)| node.clone())
+                    .collect();
+
+                return Err(Cycle { nodes });
+            }
+
+            if !finished.contains(&next) {
+                on_path.insert(next.clone());
+                let next_successors = successors(&next).into_iter();
+                stack.push((next, next_successors));
+            }
+        }
+    }
+    order.reverse();
+
+    Ok(order)
+}
+
+/// Counts the distinct paths from `start` to nodes matching `is_goal`. Paths
+/// stop at the first goal they reach. Fails if a cycle is reachable, since
+/// there would be infinitely many paths.
+pub fn count_paths<N, F, I, G>(
+    start: N,
+    mut successors: F,
+    mut is_goal: G,
+) -> Result<usize, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut edges = HashMap::new();
+    let order = topological_sort([start.clone()], |node| {
+        let next: Vec<N> = if is_goal(node) {
+            Vec::new()
+        } else {
+            successors(node).into_iter().collect()
+        };
+        edges.insert(node.clone(), next.clone());
+        next
+    })?;
+
+    // Work backwards so every successor is counted before its predecessors
+    let mut paths: HashMap<N, usize> = HashMap::new();
+    for node in order.iter().rev() {
+        let count = match edges.get(node) {
+            Some(next) if !next.is_empty() => next.iter().map(|n| paths[n]).sum(),
+            _ => usize::from(is_goal(node)),
+        };
+        paths.insert(node.clone(), count);
+    }
+
+    Ok(paths[&start])
+}
+
+/// Groups the nodes reachable from `roots` into strongly connected
+/// components, each a set of nodes that can all reach one another. Components
+/// are returned with every component after the ones it can reach.
+pub fn strongly_connected_components<N, R, F, I>(roots: R, successors: F) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    R: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut tarjan = Tarjan {
+        successors,
+        index: HashMap::new(),
+        low_link: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        components: Vec::new(),
+    };
+
+    for root in roots {
+        if !tarjan.index.contains_key(&root) {
+            tarjan.visit(root);
+        }
+    }
+
+    tarjan.components
+}
+
+struct Tarjan<N, F> {
+    successors: F,
+    index: HashMap<N, usize>,
+    low_link: HashMap<N, usize>,
+    stack: Vec<N>,
+    on_stack: HashSet<N>,
+    components: Vec<Vec<N>>,
+}
+
+impl<N, F, I> Tarjan<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn visit(&mut self, node: N) {
+        let index = self.index.len();
+        self.index.insert(node.clone(), index);
+        self.low_link.insert(node.clone(), index);
+        self.stack.push(node.clone());
+        self.on_stack.insert(node.clone());
+
+        let next: Vec<N> = (self.successors)(&node).into_iter().collect();
+        for next in next {
+            let low_link = if !self.index.contains_key(&next) {
+                self.visit(next.clone());
+                self.low_link[&next]
+            } else if self.on_stack.contains(&next) {
+                self.index[&next]
+            } else {
+                continue;
+            };
+
+            if low_link < self.low_link[&node] {
+                self.low_link.insert(node.clone(), low_link);
+            }
+        }
+
+        if self.low_link[&node] == index {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(&member);
+                let done = member == node;
+                component.push(member);
+                if done {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(parent) = parents.get(&path[path.len() - 1]) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Successors read from a list of directed edges.
+    fn edges(edges: &[(u8, u8)]) -> impl FnMut(&u8) -> Vec<u8> + '_ {
+        |node| {
+            edges
+                .iter()
+                .filter(|(from, _)| from == node)
+                .map(|(_, to)| *to)
+                .collect()
+        }
+    }
+
+    /// Successors read from a list of directed edges with costs.
+    fn weighted(edges: &[(u8, u8, u32)]) -> impl FnMut(&u8) -> Vec<(u8, u32)> + '_ {
+        |node| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect()
+        }
+    }
+
+    const DIAMOND: [(u8, u8); 4] = [(1, 2), (1, 3), (2, 4), (3, 4)];
+
+    #[test]
+    fn graph_adds_missing_nodes_with_edges() {
+        let mut graph = Graph::new();
+        graph.add_edge('a', 'b');
+        graph.add_node('c');
+
+        assert!(graph.contains_edge(&'a', &'b'));
+        assert!(!graph.contains_edge(&'b', &'a'));
+        assert_eq!(graph.successors(&'b'), &[]);
+        assert_eq!(graph.successors(&'z'), &[]);
+        let mut nodes: Vec<char> = graph.nodes().copied().collect();
+        nodes.sort_unstable();
+        assert_eq!(nodes, ['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn bfs_counts_steps_to_every_reachable_node() {
+        let distances = bfs(1, edges(&[(1, 2), (2, 3), (1, 3), (3, 4), (5, 1)]));
+
+        assert_eq!(distances, HashMap::from([(1, 0), (2, 1), (3, 1), (4, 2)]));
+    }
+
+    #[test]
+    fn bfs_path_takes_the_fewest_edges() {
+        let long_way = [(1, 2), (2, 3), (3, 4), (4, 5), (1, 6), (6, 5)];
+
+        assert_eq!(
+            bfs_path(1, edges(&long_way), |node| *node == 5),
+            Some(vec![1, 6, 5])
+        );
+        assert_eq!(
+            bfs_path(1, edges(&long_way), |node| *node == 1),
+            Some(vec![1])
+        );
+        assert_eq!(bfs_path(5, edges(&long_way), |node| *node == 1), None);
+    }
+
+    #[test]
+    fn dfs_explores_the_first_successor_first() {
+        assert_eq!(dfs(1, edges(&DIAMOND)), [1, 2, 4, 3]);
+        assert_eq!(dfs(1, edges(&[(1, 2), (2, 1)])), [1, 2]);
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path_rather_than_the_shortest() {
+        let graph = [(1, 2, 10), (1, 3, 1), (3, 4, 1), (4, 2, 1), (2, 5, 1)];
+
+        assert_eq!(
+            dijkstra(1, weighted(&graph), |node| *node == 5),
+            Some((vec![1, 3, 4, 2, 5], 4))
+        );
+        assert_eq!(
+            dijkstra(1, weighted(&graph), |node| *node == 1),
+            Some((vec![1], 0))
+        );
+        assert_eq!(dijkstra(5, weighted(&graph), |node| *node == 1), None);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_with_an_admissible_heuristic() {
+        // A 5x5 grid with a wall down the middle that has a gap at the bottom
+        let successors = |&(x, y): &(i32, i32)| {
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+                .filter(|&(x, y)| x != 2 || y == 4)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal: (i32, i32) = (4, 0);
+        let heuristic = |&(x, y): &(i32, i32)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+
+        let (path, cost) = astar((0, 0), successors, heuristic, |node| *node == goal).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[12], goal);
+        assert!(path.contains(&(2, 4)));
+        assert_eq!(
+            dijkstra((0, 0), successors, |node| *node == goal).map(|(_, cost)| cost),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn topological_sort_puts_nodes_before_their_successors() {
+        let graph = [(1, 2), (1, 3), (3, 2), (2, 4), (5, 4)];
+        let order = topological_sort([1, 5], edges(&graph)).unwrap();
+
+        assert_eq!(order.len(), 5);
+        for (from, to) in graph {
+            let position = |node| order.iter().position(|n| *n == node).unwrap();
+            assert!(position(from) < position(to), "{:?}", order);
+        }
+    }
+
+    #[test]
+    fn topological_sort_reports_the_cycle_it_found() {
+        let cycle = topological_sort([1], edges(&[(1, 2), (2, 3), (3, 4), (4, 2)]));
+
+        assert_eq!(
+            cycle,
+            Err(Cycle {
+                nodes: vec![2, 3, 4]
+            })
+        );
+    }
+
+    #[test]
+    fn count_paths_counts_each_route_to_a_goal() {
+        assert_eq!(count_paths(1, edges(&DIAMOND), |node| *node == 4), Ok(2));
+        assert_eq!(count_paths(1, edges(&DIAMOND), |node| *node == 9), Ok(0));
+        // Paths stop at the first goal, so 2 and 3 are counted but not 4
+        assert_eq!(count_paths(1, edges(&DIAMOND), |node| *node != 1), Ok(2));
+    }
+
+    #[test]
+    fn count_paths_fails_on_a_reachable_cycle() {
+        let graph = [(1, 2), (2, 3), (3, 2), (2, 4)];
+
+        assert_eq!(
+            count_paths(1, edges(&graph), |node| *node == 4),
+            Err(Cycle { nodes: vec![2, 3] })
+        );
+        // The cycle is cut off when the goal is reached first
+        assert_eq!(count_paths(1, edges(&graph), |node| *node == 2), Ok(1));
+    }
+
+    #[test]
+    fn components_come_after_the_ones_they_reach() {
+        let graph = [(1, 2), (2, 1), (2, 3), (3, 4), (4, 3), (4, 5), (6, 6)];
+        let mut components = strongly_connected_components([1, 6], edges(&graph));
+        for component in &mut components {
+            component.sort_unstable();
+        }
+
+        assert_eq!(components, [vec![5], vec![3, 4], vec![1, 2], vec![6]]);
+    }
+}
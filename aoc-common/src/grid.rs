@@ -0,0 +1,296 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse, Diagnostic, Direction, Point, Vector};
+
+/// A rectangular 2D grid stored row by row. Positions are `(x, y)` with the
+/// origin in the top left corner and `y` growing downwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a map with one row per line, converting each character with
+    /// `parse`. Columns are counted in characters, not bytes. Trailing
+    /// whitespace and blank lines at the end are ignored. Fails on characters
+    /// `parse` rejects, on blank lines and on rows whose length differs from
+    /// the first.
+    pub fn parse_with<F>(input: &str, mut parse: F) -> Result<Self, Diagnostic>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in parse::lines(input) {
+            let (line_number, line) = (line.line_number(), line.text());
+            if line.is_empty() {
+                return Err(Diagnostic::at_char(
+                    "unexpected blank line in map",
+                    line_number,
+                    line,
+                    0,
+                ));
+            }
+
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let Some(cell) = parse(c) else {
+                    return Err(Diagnostic::at_char(
+                        format!("unexpected '{}' in map", c),
+                        line_number,
+                        line,
+                        x,
+                    ));
+                };
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(Diagnostic::at_char(
+                    format!("expected {} columns, found {}", expected, row_width),
+                    line_number,
+                    line,
+                    row_width.min(expected),
+                ));
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(Diagnostic::new("expected a map", 1, "", ""));
+        };
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point`, which may be negative, lies inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        match point.to_index() {
+            Some((x, y)) => x < self.width && y < self.height,
+            None => false,
+        }
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_index()?)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.to_index()?)
+    }
+
+    /// Replaces the cell at `position`, returning `false` if it is outside the grid.
+    pub fn set(&mut self, position: (usize, usize), value: T) -> bool {
+        match self.get_mut(position) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves from `position` by `step`, or `None` if that leaves the grid.
+    pub fn offset(&self, position: (usize, usize), step: Vector) -> Option<(usize, usize)> {
+        let point = Point::from(position) + step;
+        if self.contains(point) {
+            point.to_index()
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Not `chunks`, which can't give the empty rows of a zero-width grid
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows().nth(y)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// The orthogonal neighbours of `position` that are inside the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::CARDINAL
+            .iter()
+            .filter_map(move |direction| self.offset(position, direction.vector()))
+    }
+
+    /// The orthogonal and diagonal neighbours of `position` that are inside the grid.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| self.offset(position, direction.vector()))
+    }
+
+    /// The positions of every cell matching `predicate`, row by row.
+    pub fn find_all<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Converts every cell, keeping the shape of the grid.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map of characters with one row per line.
+    pub fn parse(input: &str) -> Result<Self, Diagnostic> {
+        Self::parse_with(input, Some)
+    }
+}
+
+/// Writes the map back out in the form `parse` reads, one row per line.
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_width_grids_have_empty_rows() {
+        let grid = Grid::new(0, 2, '.');
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&[], &[]]);
+        assert_eq!(grid.row(1), Some(&[][..]));
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+    }
+
+    #[test]
+    fn rows_are_split_at_the_width() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&['a', 'b'], &['c', 'd']]);
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+}
@@ -0,0 +1,59 @@
+use std::{
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+/// Directory that puzzle inputs are loaded from, one `<day>.txt` per day.
+const INPUTS_DIR: &str = "inputs";
+
+/// Loads the input for `day`. An explicit `path` wins, then anything piped in
+/// on stdin (when `allow_stdin` is set), then `inputs/<day>.txt`.
+pub fn get_input(day: u8, path: Option<&Path>, allow_stdin: bool) -> Result<String, Error> {
+    if let Some(path) = path {
+        return read_file(path);
+    }
+
+    if allow_stdin {
+        let input = read_stdin()?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    let path = input_path(day);
+    if !path.exists() {
+        return Err(Error::new(format!(
+            "No input found for day {}: pipe it on stdin, pass --input <path> or create {}",
+            day,
+            path.display()
+        )));
+    }
+
+    read_file(&path)
+}
+
+/// The path that `day`'s input is loaded from when no override is given.
+pub fn input_path(day: u8) -> PathBuf {
+    Path::new(INPUTS_DIR).join(format!("{:02}.txt", day))
+}
+
+/// Reads all of stdin, returning an empty string when nothing was piped in.
+pub fn read_stdin() -> Result<String, Error> {
+    let mut input = String::new();
+    let stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        return Ok(input);
+    }
+
+    let mut handle = stdin.lock();
+    handle.read_to_string(&mut input)?;
+
+    Ok(input)
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("Could not read {}: {}", path.display(), e)))
+}
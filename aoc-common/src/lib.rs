@@ -0,0 +1,25 @@
+mod diagnostic;
+mod error;
+mod geometry;
+pub mod graph;
+mod grid;
+mod input;
+mod memo;
+pub mod parse;
+mod random;
+mod render;
+mod report;
+mod runner;
+mod solution;
+
+pub use diagnostic::{parse_token, Diagnostic};
+pub use error::Error;
+pub use geometry::{Direction, Point, Vector};
+pub use grid::Grid;
+pub use input::{get_input, input_path, read_stdin};
+pub use memo::{Memo, MemoStats};
+pub use random::Rng;
+pub use render::{stdout_supports_color, Cell, Color, Frames, Style};
+pub use report::{ReportFormat, Table};
+pub use runner::{run, Day, DayResult, Part, PartResult};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,201 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// A cache for recursive functions keyed on their arguments. The function
+/// body goes in the closure passed to `get_or_insert_with`, which is handed
+/// the memo back so it can make its recursive calls through the cache.
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    // Insertion order, only tracked when bounded so the oldest entry can be evicted
+    order: VecDeque<K>,
+    stats: MemoStats,
+}
+
+/// How well a `Memo` has been doing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl MemoStats {
+    /// The fraction of lookups answered from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            limit: None,
+            order: VecDeque::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// A memo holding at most `limit` entries, evicting the oldest first.
+    pub fn bounded(limit: usize) -> Self {
+        Memo {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the cached value for `key`, or computes it with `compute` and
+    /// caches it. `compute` is given the memo to use for recursive calls.
+    pub fn get_or_insert_with<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = compute(self);
+        self.insert(key, value.clone());
+
+        value
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        // A recursive call may have cached this key already, in which case it
+        // is already queued for eviction
+        if let Some(cached) = self.cache.get_mut(&key) {
+            *cached = value;
+            return;
+        }
+
+        if let Some(limit) = self.limit {
+            if limit == 0 {
+                return;
+            }
+
+            while self.cache.len() >= limit {
+                let Some(oldest) = self.order.pop_front() else {
+                    break;
+                };
+                self.cache.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+            self.order.push_back(key.clone());
+        }
+
+        self.cache.insert(key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Looks `key` up, caching the key itself as the value on a miss.
+    fn get(memo: &mut Memo<u32, u32>, key: u32) -> u32 {
+        memo.get_or_insert_with(key, |_| key)
+    }
+
+    #[test]
+    fn unbounded_memo_keeps_everything() {
+        let mut memo = Memo::new();
+        for key in [1, 2, 3, 1, 2, 3] {
+            get(&mut memo, key);
+        }
+
+        assert_eq!(memo.len(), 3);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 3,
+                misses: 3,
+                evictions: 0
+            }
+        );
+        assert_eq!(memo.stats().hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn bounded_memo_evicts_the_oldest_entry_first() {
+        let mut memo = Memo::bounded(2);
+        for key in [1, 2, 3] {
+            get(&mut memo, key);
+        }
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.stats().evictions, 1);
+
+        // 2 and 3 are still there, 1 is not
+        get(&mut memo, 3);
+        get(&mut memo, 2);
+        assert_eq!(memo.stats().hits, 2);
+        get(&mut memo, 1);
+        assert_eq!(memo.stats().misses, 4);
+        assert_eq!(memo.stats().evictions, 2);
+    }
+
+    #[test]
+    fn memo_bounded_to_nothing_never_caches() {
+        let mut memo = Memo::bounded(0);
+        get(&mut memo, 1);
+        get(&mut memo, 1);
+
+        assert!(memo.is_empty());
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 0,
+                misses: 2,
+                evictions: 0
+            }
+        );
+    }
+
+    #[test]
+    fn keys_cached_by_a_recursive_call_are_only_queued_once() {
+        let mut memo = Memo::bounded(2);
+        memo.get_or_insert_with(1, |memo| get(memo, 1));
+        get(&mut memo, 2);
+        get(&mut memo, 3);
+        get(&mut memo, 4);
+
+        // Only 1 then 2 were evicted, leaving 3 and 4
+        assert_eq!(memo.stats().evictions, 2);
+        assert_eq!(memo.len(), 2);
+        get(&mut memo, 3);
+        get(&mut memo, 4);
+        assert_eq!(memo.stats().hits, 2);
+    }
+
+    #[test]
+    fn hit_rate_is_zero_before_any_lookups() {
+        assert_eq!(MemoStats::default().hit_rate(), 0.0);
+    }
+}
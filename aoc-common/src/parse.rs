@@ -0,0 +1,193 @@
+//! Parsers for the shapes puzzle inputs come in: lines of numbers, delimited
+//! records, blank-line separated sections and digit strings. Every piece of
+//! text is a `Span` that remembers where it came from, so failures point at
+//! the offending token. Line endings may be `\n` or `\r\n`, and a leading
+//! byte order mark and trailing whitespace are ignored.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{parse_token, Diagnostic, Grid};
+
+/// A piece of one line of the input.
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    line_number: usize,
+    line: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    fn line(line_number: usize, line: &'a str) -> Self {
+        Span {
+            line_number,
+            line,
+            text: line,
+        }
+    }
+
+    fn sub(&self, text: &'a str) -> Self {
+        Span { text, ..*self }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// 1-based line number in the input.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// A diagnostic pointing at this span.
+    pub fn error(&self, message: impl Into<String>) -> Diagnostic {
+        Diagnostic::new(message, self.line_number, self.line, self.text)
+    }
+
+    /// Parses the whole span, ignoring surrounding whitespace.
+    pub fn parse<T>(&self) -> Result<T, Diagnostic>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_token(self.line_number, self.line, self.text.trim())
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.text.split_whitespace().map(move |text| span.sub(text))
+    }
+
+    /// Splits on `delimiter`, trimming whitespace around each piece.
+    pub fn split(&self, delimiter: char) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.text
+            .split(delimiter)
+            .map(move |text| span.sub(text.trim()))
+    }
+
+    /// Splits on the first `delimiter`, trimming whitespace around both halves.
+    pub fn split_once(&self, delimiter: char) -> Result<(Span<'a>, Span<'a>), Diagnostic> {
+        match self.text.split_once(delimiter) {
+            Some((first, second)) => Ok((self.sub(first.trim()), self.sub(second.trim()))),
+            None => Err(self.error(format!("expected '{}'", delimiter))),
+        }
+    }
+
+    /// Whitespace-separated values such as `7 6 4 2 1`.
+    pub fn numbers<T>(&self) -> Result<Vec<T>, Diagnostic>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split_whitespace().map(|span| span.parse()).collect()
+    }
+
+    /// Values separated by `delimiter` such as `75,47,61`.
+    pub fn list<T>(&self, delimiter: char) -> Result<Vec<T>, Diagnostic>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(delimiter).map(|span| span.parse()).collect()
+    }
+
+    /// Two values separated by `delimiter` such as `47|53`.
+    pub fn pair<A, B>(&self, delimiter: char) -> Result<(A, B), Diagnostic>
+    where
+        A: FromStr,
+        A::Err: Display,
+        B: FromStr,
+        B::Err: Display,
+    {
+        let (first, second) = self.split_once(delimiter)?;
+
+        Ok((first.parse()?, second.parse()?))
+    }
+
+    /// Every character as a decimal digit, such as `2333133121414131402`.
+    pub fn digits(&self) -> Result<Vec<u8>, Diagnostic> {
+        let mut digits = Vec::new();
+        for (i, c) in self.text.char_indices() {
+            match c.to_digit(10) {
+                Some(digit) => digits.push(digit as u8),
+                None => {
+                    return Err(self
+                        .sub(&self.text[i..i + c.len_utf8()])
+                        .error(format!("expected a digit, found '{}'", c)))
+                }
+            }
+        }
+
+        Ok(digits)
+    }
+}
+
+/// The lines of `input` with trailing whitespace removed. Blank lines at the
+/// end are dropped.
+pub fn lines(input: &str) -> Vec<Span<'_>> {
+    // Editors that write `\r\n` often start the file with a byte order mark
+    let input = input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(input);
+
+    let mut lines: Vec<Span> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Span::line(i + 1, line.trim_end()))
+        .collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+/// The groups of lines in `input` separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+    for line in lines(input) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                sections.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        sections.push(current);
+    }
+
+    sections
+}
+
+/// Parses one record from each line, stopping at the first failure.
+pub fn records<'a, T, L, F>(lines: L, parse: F) -> Result<Vec<T>, Diagnostic>
+where
+    L: IntoIterator<Item = Span<'a>>,
+    F: FnMut(Span<'a>) -> Result<T, Diagnostic>,
+{
+    lines.into_iter().map(parse).collect()
+}
+
+/// An input that is a single line of digits.
+pub fn digit_line(input: &str) -> Result<Vec<u8>, Diagnostic> {
+    match lines(input).as_slice() {
+        [line] => line.digits(),
+        [] => Err(Diagnostic::new("expected a line of digits", 1, "", "")),
+        [_, extra, ..] => Err(extra.error("expected a single line of digits")),
+    }
+}
+
+/// An input that is a rectangular grid of digits.
+pub fn digit_grid(input: &str) -> Result<Grid<u8>, Diagnostic> {
+    Grid::parse_with(input, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
@@ -0,0 +1,59 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded random number generator (SplitMix64) for generating puzzle
+/// inputs. The same seed always produces the same sequence, on every platform
+/// and across releases, so a seed is enough to reproduce an input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// A value from `0` up to but not including `bound`, which must not be 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        let span = end - start;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+
+        start + ((self.next_u64() as u128 * (span + 1) as u128) >> 64) as u64
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // An f64 holds 53 bits exactly, so keep the top 53
+        let sample = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+
+        sample < probability
+    }
+
+    /// A random item of `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
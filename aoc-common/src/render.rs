@@ -0,0 +1,260 @@
+use std::{
+    fmt::Write,
+    io::{stdout, IsTerminal},
+};
+
+use crate::{Error, Grid};
+
+/// A terminal colour: one of the 16 standard ANSI colours or an entry in the
+/// 256-colour palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Palette(u8),
+}
+
+impl Color {
+    /// Colours that are easy to tell apart, for things like antenna frequencies.
+    pub const DISTINCT: [Color; 12] = [
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// A shade of grey from the 256-colour palette, with 0 darkest and 23 lightest.
+    pub fn grey(level: u8) -> Self {
+        Color::Palette(232 + level.min(23))
+    }
+
+    /// The colour's index in the 256-colour palette.
+    pub fn index(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+            Color::Palette(index) => index,
+        }
+    }
+}
+
+/// How a cell is drawn. The default is the terminal's own colours.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    /// Faint, for cells that are only there to fill in the picture.
+    pub dim: bool,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Style {
+            foreground: None,
+            background: None,
+            bold: false,
+            dim: false,
+        }
+    }
+
+    pub const fn foreground(self, color: Color) -> Self {
+        Style {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn background(self, color: Color) -> Self {
+        Style {
+            background: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    pub const fn dim(self) -> Self {
+        Style { dim: true, ..self }
+    }
+
+    fn write_escape(&self, output: &mut String) {
+        output.push_str("\x1b[0");
+        if self.bold {
+            output.push_str(";1");
+        }
+        if self.dim {
+            output.push_str(";2");
+        }
+        if let Some(color) = self.foreground {
+            let _ = write!(output, ";38;5;{}", color.index());
+        }
+        if let Some(color) = self.background {
+            let _ = write!(output, ";48;5;{}", color.index());
+        }
+        output.push('m');
+    }
+}
+
+/// One character of a rendered grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const fn new(symbol: char, style: Style) -> Self {
+        Cell { symbol, style }
+    }
+
+    pub const fn plain(symbol: char) -> Self {
+        Cell::new(symbol, Style::new())
+    }
+}
+
+impl Grid<Cell> {
+    /// Draws the grid one row per line, using ANSI escapes for styling when
+    /// `color` is set and plain characters otherwise.
+    pub fn to_ansi(&self, color: bool) -> String {
+        let mut output = String::new();
+        for row in self.rows() {
+            let mut current = Style::new();
+            for cell in row {
+                if color && cell.style != current {
+                    cell.style.write_escape(&mut output);
+                    current = cell.style;
+                }
+                output.push(cell.symbol);
+            }
+
+            if color && current != Style::new() {
+                output.push_str("\x1b[0m");
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// Where `Frames` sends each frame it keeps.
+type FrameSink<'a> = Box<dyn FnMut(&Grid<Cell>) -> Result<(), Error> + 'a>;
+
+/// The frames of a simulation, sampled so that long runs stay a manageable
+/// size: only every `every`th frame offered is kept, plus the final one, and
+/// no more than `limit` in all. Kept frames go straight to `sink` rather than
+/// being held, so a long simulation never has more than one in memory.
+pub struct Frames<'a> {
+    every: usize,
+    limit: usize,
+    offered: usize,
+    kept: usize,
+    // Whether the most recently offered frame was dropped by the sampling
+    pending: bool,
+    sink: FrameSink<'a>,
+    // The first error from `sink`, after which nothing more is drawn
+    error: Option<Error>,
+}
+
+impl<'a> Frames<'a> {
+    pub fn new<F>(every: usize, limit: usize, sink: F) -> Self
+    where
+        F: FnMut(&Grid<Cell>) -> Result<(), Error> + 'a,
+    {
+        Frames {
+            every: every.max(1),
+            limit: limit.max(1),
+            offered: 0,
+            kept: 0,
+            pending: false,
+            sink: Box::new(sink),
+            error: None,
+        }
+    }
+
+    /// Offers the next frame. `draw` is only called if the frame is kept.
+    pub fn push_with<F>(&mut self, draw: F)
+    where
+        F: FnOnce() -> Grid<Cell>,
+    {
+        // The last of the limit is saved for the final frame
+        self.pending = !self.offered.is_multiple_of(self.every) || self.kept + 1 >= self.limit;
+        if !self.pending && self.error.is_none() {
+            self.keep(draw());
+        }
+        self.offered += 1;
+    }
+
+    /// Offers the final frame, which is kept unless it was the last one
+    /// already kept.
+    pub fn finish_with<F>(&mut self, draw: F)
+    where
+        F: FnOnce() -> Grid<Cell>,
+    {
+        if (self.pending || self.kept == 0) && self.error.is_none() {
+            self.keep(draw());
+            self.pending = false;
+        }
+    }
+
+    /// The number of frames given to the sink, or the first error it
+    /// returned.
+    pub fn finish(self) -> Result<usize, Error> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.kept),
+        }
+    }
+
+    fn keep(&mut self, frame: Grid<Cell>) {
+        match (self.sink)(&frame) {
+            Ok(()) => self.kept += 1,
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+/// Whether stdout is a terminal that should get colour. Setting `NO_COLOR`
+/// turns colour off.
+pub fn stdout_supports_color() -> bool {
+    stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+/// How `aoc report` writes out a day's tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Columns padded to line up, for reading in a terminal.
+    Table,
+    /// Comma-separated values, for loading into something else.
+    Csv,
+    /// One JSON object per row, each on its own line.
+    Json,
+}
+
+/// Rows of text cells, the first of which is the header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table {
+    rows: Vec<Vec<String>>,
+    // What each row after the header is as JSON, if it is more than its cells
+    records: Vec<Option<String>>,
+}
+
+impl Table {
+    pub fn new<I, S>(header: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Table {
+            rows: vec![header.into_iter().map(|cell| cell.to_string()).collect()],
+            records: Vec::new(),
+        }
+    }
+
+    pub fn push_row<I, S>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+        self.records.push(None);
+    }
+
+    /// Adds a row that is written as `record` in JSON rather than as its
+    /// cells.
+    pub fn push_record<I, S, R>(&mut self, row: I, record: &R)
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+        R: Serialize,
+    {
+        self.push_row(row);
+        // Serializing only fails for maps with non-string keys
+        *self.records.last_mut().unwrap() = Some(serde_json::to_string(record).unwrap());
+    }
+
+    pub fn format(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Table => self.to_text(),
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Json => self.to_json(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut widths = Vec::new();
+        for row in &self.rows {
+            widths.resize(widths.len().max(row.len()), 0);
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut text = String::new();
+        for row in &self.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            text += cells.join("  ").trim_end();
+            text.push('\n');
+        }
+
+        text
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().map(|cell| csv_field(cell)).collect();
+            csv += &cells.join(",");
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    fn to_json(&self) -> String {
+        let Some((header, rows)) = self.rows.split_first() else {
+            return String::new();
+        };
+
+        let mut json = String::new();
+        for (row, record) in rows.iter().zip(&self.records) {
+            match record {
+                Some(record) => json += record,
+                None => {
+                    // Written by hand to keep the fields in column order
+                    let fields: Vec<String> = header
+                        .iter()
+                        .zip(row)
+                        .map(|(name, cell)| format!("{}:{}", json_string(name), json_string(cell)))
+                        .collect();
+                    json += &format!("{{{}}}", fields.join(","));
+                }
+            }
+            json.push('\n');
+        }
+
+        json
+    }
+}
+
+fn json_string(text: &str) -> String {
+    // Serializing a string cannot fail
+    serde_json::to_string(text).unwrap()
+}
+
+/// Quotes `cell` if it would otherwise be split or misread.
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use tracing::{info, info_span};
+
+use crate::{Answer, Cell, Error, Frames, Grid, Rng, Solution, Table};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn parse(part: u8) -> Result<Self, Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(Error::new(format!("Invalid part: {}", part))),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A registered day, with its `Solution` erased so days can be stored together.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    runner: fn(u8, &str, &[Part]) -> Result<DayResult, Error>,
+    renderer: fn(&str) -> Result<Option<Grid<Cell>>, Error>,
+    animator: fn(&str, &mut Frames) -> Result<(), Error>,
+    reporter: fn(&str) -> Result<Vec<Table>, Error>,
+    generator: fn(&mut Rng, Option<usize>) -> Option<String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Self {
+        Day {
+            number,
+            runner: run::<S>,
+            renderer: render::<S>,
+            animator: animate::<S>,
+            reporter: report::<S>,
+            generator: S::generate,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<DayResult, Error> {
+        (self.runner)(self.number, input, parts)
+    }
+
+    /// Parses `input` and draws it, or `None` if the day has no renderer.
+    pub fn render(&self, input: &str) -> Result<Option<Grid<Cell>>, Error> {
+        (self.renderer)(input)
+    }
+
+    /// Parses `input` and simulates it, adding the states to `frames`. Days
+    /// without a simulation add nothing.
+    pub fn animate(&self, input: &str, frames: &mut Frames) -> Result<(), Error> {
+        (self.animator)(input, frames)
+    }
+
+    /// Parses `input` and analyses it, returning no tables if the day has no
+    /// report.
+    pub fn report(&self, input: &str) -> Result<Vec<Table>, Error> {
+        (self.reporter)(input)
+    }
+
+    /// A random input generated from `seed`, or `None` if the day has no
+    /// generator. See `Solution::generate` for what `size` means.
+    pub fn generate(&self, seed: u64, size: Option<usize>) -> Option<String> {
+        (self.generator)(&mut Rng::new(seed), size)
+    }
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// Parses `input` once and runs each of the requested parts against it.
+pub fn run<S: Solution>(day: u8, input: &str, parts: &[Part]) -> Result<DayResult, Error> {
+    let _day = info_span!("day", day).entered();
+
+    let start = Instant::now();
+    let model = info_span!("parse").in_scope(|| S::parse(input))?;
+    let parse = start.elapsed();
+    info!(elapsed = ?parse, "parsed input");
+
+    let mut results = Vec::new();
+    for part in parts {
+        let _part = info_span!("part", part = %part).entered();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&model)?,
+            Part::Two => S::part2(&model)?,
+        };
+        let duration = start.elapsed();
+        info!(%answer, elapsed = ?duration, "solved");
+
+        results.push(PartResult {
+            part: *part,
+            answer,
+            duration,
+        });
+    }
+
+    Ok(DayResult {
+        day,
+        parse,
+        parts: results,
+    })
+}
+
+fn render<S: Solution>(input: &str) -> Result<Option<Grid<Cell>>, Error> {
+    S::render(&S::parse(input)?)
+}
+
+fn animate<S: Solution>(input: &str, frames: &mut Frames) -> Result<(), Error> {
+    S::animate(&S::parse(input)?, frames)
+}
+
+fn report<S: Solution>(input: &str) -> Result<Vec<Table>, Error> {
+    S::report(&S::parse(input)?)
+}
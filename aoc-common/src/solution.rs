@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::{Cell, Error, Frames, Grid, Rng, Table};
+
+/// A single day's puzzle. The input is parsed once into `Model`, which both
+/// parts then share.
+pub trait Solution {
+    type Model;
+
+    fn parse(input: &str) -> Result<Self::Model, Error>;
+
+    fn part1(model: &Self::Model) -> Result<Answer, Error>;
+
+    fn part2(model: &Self::Model) -> Result<Answer, Error>;
+
+    /// Draws the model for debugging. Days without anything worth drawing
+    /// keep the default.
+    fn render(_model: &Self::Model) -> Result<Option<Grid<Cell>>, Error> {
+        Ok(None)
+    }
+
+    /// Steps through a simulation of the model, offering each state to
+    /// `frames`. Days without a simulation keep the default and offer none.
+    fn animate(_model: &Self::Model, _frames: &mut Frames) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Extra analyses of the model beyond the two answers, as tables. Days
+    /// with nothing more to say keep the default and return none.
+    fn report(_model: &Self::Model) -> Result<Vec<Table>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// A random input in the same format as the puzzle's, of roughly `size`
+    /// lines or cells depending on the day, or the size of a real input if
+    /// `size` is `None`. Days without a generator return `None`.
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
@@ -0,0 +1,37 @@
+use std::{collections::HashSet, env, fs, path::Path};
+
+// Generates one test per entry in the workspace's answers.toml, which
+// tests/answers.rs includes.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest = Path::new(&manifest_dir).join("..").join("answers.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let contents = fs::read_to_string(&manifest).unwrap();
+    let table: toml::Table = contents.parse().unwrap();
+
+    let mut tests = String::new();
+    let mut names = HashSet::new();
+    let answers = table["answers"].as_array().unwrap();
+    for (index, entry) in answers.iter().enumerate() {
+        let day = entry["day"].as_integer().unwrap();
+        let input = entry["input"].as_str().unwrap();
+
+        let mut name = format!("day{:02}_{}", day, test_name(input));
+        if !names.insert(name.clone()) {
+            name = format!("{}_{}", name, index);
+        }
+
+        tests += &format!("#[test]\nfn {}() {{\n    check({});\n}}\n\n", name, index);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("answer_tests.rs"), tests).unwrap();
+}
+
+fn test_name(input: &str) -> String {
+    let stem = input.strip_suffix(".txt").unwrap_or(input);
+    stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
@@ -0,0 +1,133 @@
+use std::{path::Path, time::Duration};
+
+use aoc_common::{Day, Error, Part};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub phases: Vec<PhaseBench>,
+}
+
+/// Timings for one phase of a day (`parse`, `part1` or `part2`), in nanoseconds.
+#[derive(Serialize, Deserialize)]
+pub struct PhaseBench {
+    pub phase: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl PhaseBench {
+    fn new(phase: &str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        PhaseBench {
+            phase: phase.to_string(),
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: samples[samples.len() / 2].as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+        }
+    }
+}
+
+pub struct Regression {
+    pub day: u8,
+    pub phase: String,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.current_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0
+    }
+}
+
+/// Runs `day` against `input` `iterations` times, timing the parse and each
+/// requested part separately.
+pub fn bench(day: &Day, input: &str, parts: &[Part], iterations: usize) -> Result<DayBench, Error> {
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+
+    for _ in 0..iterations {
+        let result = day.run(input, parts)?;
+        parse.push(result.parse);
+        for part in result.parts {
+            match part.part {
+                Part::One => part1.push(part.duration),
+                Part::Two => part2.push(part.duration),
+            }
+        }
+    }
+
+    let mut phases = vec![PhaseBench::new("parse", parse)];
+    if !part1.is_empty() {
+        phases.push(PhaseBench::new("part1", part1));
+    }
+    if !part2.is_empty() {
+        phases.push(PhaseBench::new("part2", part2));
+    }
+
+    Ok(DayBench {
+        day: day.number,
+        phases,
+    })
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("Could not read {}: {}", path.display(), e)))?;
+
+        serde_json::from_str(&contents)
+            .map_err(|e| Error::new(format!("Invalid report {}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::new(format!("Could not serialize report: {}", e)))?;
+
+        std::fs::write(path, contents)
+            .map_err(|e| Error::new(format!("Could not write {}: {}", path.display(), e)))
+    }
+
+    /// Finds every phase whose median is more than `threshold` percent slower
+    /// than the same phase in `baseline`. Phases missing from either report
+    /// are ignored.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for day in &self.days {
+            let Some(baseline_day) = baseline.days.iter().find(|d| d.day == day.day) else {
+                continue;
+            };
+
+            for phase in &day.phases {
+                let Some(baseline_phase) =
+                    baseline_day.phases.iter().find(|p| p.phase == phase.phase)
+                else {
+                    continue;
+                };
+
+                let limit = baseline_phase.median_ns as f64 * (1.0 + threshold / 100.0);
+                if phase.median_ns as f64 > limit {
+                    regressions.push(Regression {
+                        day: day.day,
+                        phase: phase.phase.clone(),
+                        baseline_ns: baseline_phase.median_ns,
+                        current_ns: phase.median_ns,
+                    });
+                }
+            }
+        }
+
+        regressions
+    }
+}
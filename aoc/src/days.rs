@@ -0,0 +1,62 @@
+use aoc_common::{Day, Error};
+
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<Day01>(1),
+    Day::new::<Day02>(2),
+    Day::new::<Day03>(3),
+    Day::new::<Day04>(4),
+    Day::new::<Day05>(5),
+    Day::new::<Day06>(6),
+    Day::new::<Day07>(7),
+    Day::new::<Day08>(8),
+    Day::new::<Day09>(9),
+    Day::new::<Day10>(10),
+    Day::new::<Day11>(11),
+];
+
+pub fn find(number: u8) -> Option<Day> {
+    DAYS.iter().find(|day| day.number == number).copied()
+}
+
+/// Selects the registered days matching `selection`, which is either `all`,
+/// a single day (`7`) or an inclusive range (`3-6`).
+pub fn select(selection: &str) -> Result<Vec<Day>, Error> {
+    if selection == "all" {
+        return Ok(DAYS.to_vec());
+    }
+
+    let (first, last) = match selection.split_once('-') {
+        Some((first, last)) => (first.parse::<u8>()?, last.parse::<u8>()?),
+        None => {
+            let day = selection.parse::<u8>()?;
+            (day, day)
+        }
+    };
+
+    let days: Vec<Day> = DAYS
+        .iter()
+        .filter(|day| day.number >= first && day.number <= last)
+        .copied()
+        .collect();
+
+    if days.is_empty() {
+        return Err(Error::new(format!(
+            "No registered days match '{}'",
+            selection
+        )));
+    }
+
+    Ok(days)
+}
@@ -0,0 +1,252 @@
+//! Image output for rendered grids: a PNG of one state or an animated GIF of a
+//! simulation. Each cell becomes a `scale` by `scale` square filled with its
+//! background colour if it has one and its foreground colour otherwise.
+
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use aoc_common::{Cell, Color, Error, Grid};
+
+/// The RGB values to draw each terminal colour with, and the colour of cells
+/// that don't have one.
+pub struct Palette {
+    colors: [[u8; 3]; 256],
+    background: Color,
+}
+
+impl Palette {
+    /// The standard xterm colours on black.
+    pub fn xterm() -> Self {
+        let mut colors = [[0; 3]; 256];
+        colors[..16].copy_from_slice(&XTERM_BASE);
+
+        // A 6x6x6 colour cube followed by a ramp of greys
+        for (cube, color) in colors[16..232].iter_mut().enumerate() {
+            *color = [
+                CUBE_LEVELS[cube / 36],
+                CUBE_LEVELS[cube / 6 % 6],
+                CUBE_LEVELS[cube % 6],
+            ];
+        }
+        for (level, color) in colors[232..].iter_mut().enumerate() {
+            *color = [8 + 10 * level as u8; 3];
+        }
+
+        Palette {
+            colors,
+            background: Color::Black,
+        }
+    }
+
+    /// The Solarized dark terminal colours.
+    pub fn solarized() -> Self {
+        let mut palette = Palette::xterm();
+        palette.colors[..16].copy_from_slice(&SOLARIZED_BASE);
+        palette.background = Color::BrightBlack;
+
+        palette
+    }
+
+    /// The xterm colours reduced to their brightness.
+    pub fn greyscale() -> Self {
+        let mut palette = Palette::xterm();
+        for color in &mut palette.colors {
+            let [r, g, b] = color.map(f64::from);
+            let luma = (0.299 * r + 0.587 * g + 0.114 * b).round() as u8;
+            *color = [luma; 3];
+        }
+
+        palette
+    }
+
+    fn fill(&self, cell: &Cell) -> u8 {
+        cell.style
+            .background
+            .or(cell.style.foreground)
+            .unwrap_or(self.background)
+            .index()
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.colors.iter().flatten().copied().collect()
+    }
+}
+
+/// Writes `grid` to `path` as a PNG.
+pub fn write_png(
+    path: &Path,
+    grid: &Grid<Cell>,
+    scale: usize,
+    palette: &Palette,
+) -> Result<(), Error> {
+    let (width, height, pixels) = pixels(grid, scale, palette);
+    let mut encoder = png::Encoder::new(create(path)?, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.rgb());
+
+    let mut writer = encoder.write_header().map_err(|e| write_error(path, e))?;
+    writer
+        .write_image_data(&pixels)
+        .map_err(|e| write_error(path, e))?;
+    writer.finish().map_err(|e| write_error(path, e))
+}
+
+/// A looping GIF written one frame at a time, showing each frame for `delay`
+/// hundredths of a second. The file is created when the first frame arrives,
+/// as that fixes the image size.
+pub struct GifWriter<'a> {
+    path: &'a Path,
+    scale: usize,
+    delay: u16,
+    palette: &'a Palette,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    // The width and height in cells of the first frame
+    size: (usize, usize),
+}
+
+impl<'a> GifWriter<'a> {
+    pub fn new(path: &'a Path, scale: usize, delay: u16, palette: &'a Palette) -> Self {
+        GifWriter {
+            path,
+            scale,
+            delay,
+            palette,
+            encoder: None,
+            size: (0, 0),
+        }
+    }
+
+    /// Appends `frame`, which must be the same size as the first.
+    pub fn write_frame(&mut self, frame: &Grid<Cell>) -> Result<(), Error> {
+        let (width, height) = (frame.width() * self.scale, frame.height() * self.scale);
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(Error::new(format!(
+                "A {}x{} image is too large for a GIF, try a smaller --scale",
+                width, height
+            )));
+        };
+
+        let size = (frame.width(), frame.height());
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => {
+                let mut encoder =
+                    gif::Encoder::new(create(self.path)?, width, height, &self.palette.rgb())
+                        .map_err(|e| write_error(self.path, e))?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(|e| write_error(self.path, e))?;
+                self.size = size;
+                self.encoder.insert(encoder)
+            }
+        };
+        if size != self.size {
+            return Err(Error::new(format!(
+                "Frames changed size from {}x{} to {}x{}",
+                self.size.0, self.size.1, size.0, size.1
+            )));
+        }
+
+        let (_, _, pixels) = pixels(frame, self.scale, self.palette);
+        let frame = gif::Frame {
+            width,
+            height,
+            delay: self.delay,
+            buffer: Cow::Owned(pixels),
+            ..gif::Frame::default()
+        };
+        encoder
+            .write_frame(&frame)
+            .map_err(|e| write_error(self.path, e))
+    }
+
+    /// Ends the GIF. Nothing is written if there were no frames.
+    pub fn finish(self) -> Result<(), Error> {
+        let Some(encoder) = self.encoder else {
+            return Ok(());
+        };
+
+        encoder
+            .into_inner()
+            .and_then(|mut file| file.flush())
+            .map_err(|e| write_error(self.path, e))
+    }
+}
+
+/// The grid scaled up to an image, returned as its width, height and the
+/// palette index of every pixel in row-major order.
+fn pixels(grid: &Grid<Cell>, scale: usize, palette: &Palette) -> (usize, usize, Vec<u8>) {
+    let width = grid.width() * scale;
+    let height = grid.height() * scale;
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in grid.rows() {
+        let mut line = Vec::with_capacity(width);
+        for cell in row {
+            let fill = palette.fill(cell);
+            line.extend(std::iter::repeat_n(fill, scale));
+        }
+
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    (width, height, pixels)
+}
+
+fn write_error(path: &Path, e: impl fmt::Display) -> Error {
+    Error::new(format!("Could not write {}: {}", path.display(), e))
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, Error> {
+    let file = File::create(path).map_err(|e| write_error(path, e))?;
+
+    Ok(BufWriter::new(file))
+}
+
+const XTERM_BASE: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+const SOLARIZED_BASE: [[u8; 3]; 16] = [
+    [7, 54, 66],
+    [220, 50, 47],
+    [133, 153, 0],
+    [181, 137, 0],
+    [38, 139, 210],
+    [211, 54, 130],
+    [42, 161, 152],
+    [238, 232, 213],
+    [0, 43, 54],
+    [203, 75, 22],
+    [88, 110, 117],
+    [101, 123, 131],
+    [131, 148, 150],
+    [108, 113, 196],
+    [147, 161, 161],
+    [253, 246, 227],
+];
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
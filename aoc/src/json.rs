@@ -0,0 +1,33 @@
+use aoc_common::DayResult;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// One line of `--format json` output: a single part of a single day.
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_ns: u64,
+    part_ns: u64,
+    input_sha256: String,
+}
+
+/// Prints one JSON record per part, each on its own line.
+pub fn print_records(result: &DayResult, input: &str) {
+    let input_sha256 = format!("{:x}", Sha256::digest(input.as_bytes()));
+
+    for part in &result.parts {
+        let record = Record {
+            day: result.day,
+            part: part.part.number(),
+            answer: part.answer.to_string(),
+            parse_ns: result.parse.as_nanos() as u64,
+            part_ns: part.duration.as_nanos() as u64,
+            input_sha256: input_sha256.clone(),
+        };
+
+        // Serializing a struct of plain fields cannot fail
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+}
@@ -0,0 +1,579 @@
+use std::path::{Path, PathBuf};
+
+use aoc::{
+    bench::{self, Report},
+    days,
+    export::{self, Palette},
+    scaffold,
+    verify::{self, Manifest, MANIFEST},
+};
+use aoc_common::{get_input, stdout_supports_color, Day, Error, Frames, Part, ReportFormat};
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing_subscriber::EnvFilter;
+
+mod json;
+mod table;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show timings (`-v`), solver debug output (`-vv`) or everything
+    /// (`-vvv`). `RUST_LOG` takes precedence when set.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, a range of days (`3-6`) or `all`
+    Run {
+        /// A day number, an inclusive range of days or `all`
+        days: String,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead of `inputs/<day>.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Print a table, or one JSON record per part with nothing else on stdout
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Time the parse and each part of one or more days over several iterations
+    Bench {
+        /// A day number, an inclusive range of days or `all`
+        days: String,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead of `inputs/<day>.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Number of times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Write the report as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Compare against a JSON report from a previous run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Slowdown of a phase's median, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check the solutions against the known answers in the manifest
+    Verify {
+        /// A day number, an inclusive range of days or `all`
+        #[arg(default_value = "all")]
+        days: String,
+
+        /// Manifest of inputs and their expected answers
+        #[arg(long, default_value = MANIFEST)]
+        manifest: PathBuf,
+    },
+    /// Draw a day's parsed input, for the days that have something to show
+    Render {
+        /// The day to draw
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Read the input from this file instead of `inputs/<day>.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Colour the output; `auto` only does so when stdout is a terminal
+        #[arg(long, value_enum, default_value_t = ColorMode::Auto)]
+        color: ColorMode,
+    },
+    /// Save a day's drawing as a PNG, or its simulation as an animated GIF
+    Export {
+        /// The day to draw
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Where to save the image, ending in `.png` or `.gif`
+        output: PathBuf,
+
+        /// Read the input from this file instead of `inputs/<day>.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Width and height of each cell in pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+
+        /// Colours to draw with
+        #[arg(long, value_enum, default_value_t = PaletteName::Xterm)]
+        palette: PaletteName,
+
+        /// Only keep every this many steps of a simulation
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+
+        /// Stop keeping steps after this many frames, apart from the final one
+        #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(2..))]
+        max_frames: u64,
+
+        /// How long each GIF frame is shown, in hundredths of a second
+        #[arg(long, default_value_t = 5)]
+        delay: u16,
+    },
+    /// Print a day's extra analyses of its input, for the days that have any
+    Report {
+        /// The day to analyse
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Read the input from this file instead of `inputs/<day>.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Line the columns up, print comma-separated values, or print one
+        /// JSON object per row
+        #[arg(long, value_enum, default_value_t = ReportFormatName::Table)]
+        format: ReportFormatName,
+    },
+    /// Print a random input for a day, or write it to a file
+    Generate {
+        /// The day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The same seed always generates the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many lines or cells to generate, depending on the day;
+        /// defaults to the size of a real input
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Create a new day from the template crate and register it with the runner
+    New {
+        /// The day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormatName {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PaletteName {
+    Xterm,
+    Solarized,
+    Greyscale,
+}
+
+/// Which steps of a simulation `aoc export` keeps as GIF frames.
+#[derive(Clone, Copy)]
+struct Sampling {
+    every: usize,
+    max_frames: usize,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+
+    if let Err(e) = run(cli) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => run_days(&days, part, input.as_deref(), format),
+        Command::Bench {
+            days,
+            part,
+            input,
+            iterations,
+            output,
+            baseline,
+            threshold,
+        } => bench_days(
+            &days,
+            part,
+            input.as_deref(),
+            iterations as usize,
+            output.as_deref(),
+            baseline.as_deref(),
+            threshold,
+        ),
+        Command::Verify { days, manifest } => verify_days(&days, &manifest),
+        Command::Render { day, input, color } => render_day(day, input.as_deref(), color),
+        Command::Export {
+            day,
+            output,
+            input,
+            scale,
+            palette,
+            every,
+            max_frames,
+            delay,
+        } => {
+            let palette = match palette {
+                PaletteName::Xterm => Palette::xterm(),
+                PaletteName::Solarized => Palette::solarized(),
+                PaletteName::Greyscale => Palette::greyscale(),
+            };
+            export_day(
+                day,
+                &output,
+                input.as_deref(),
+                scale as usize,
+                &palette,
+                Sampling {
+                    every: every as usize,
+                    max_frames: max_frames as usize,
+                },
+                delay,
+            )
+        }
+        Command::Report { day, input, format } => {
+            let format = match format {
+                ReportFormatName::Table => ReportFormat::Table,
+                ReportFormatName::Csv => ReportFormat::Csv,
+                ReportFormatName::Json => ReportFormat::Json,
+            };
+            report_day(day, input.as_deref(), format)
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        } => generate_day(day, seed, size, output.as_deref()),
+        Command::New { day } => new_day(day),
+    }
+}
+
+fn run_days(
+    days: &str,
+    part: Option<u8>,
+    input: Option<&Path>,
+    format: Format,
+) -> Result<(), Error> {
+    let parts = select_parts(part)?;
+    let days = select_days(days, input)?;
+
+    let mut results = Vec::new();
+    for day in &days {
+        // Piped input only makes sense for a single day
+        let input = get_input(day.number, input, days.len() == 1)?;
+        let result = day.run(&input, &parts)?;
+        match format {
+            // Stream records so a slow day doesn't hold back earlier answers
+            Format::Json => json::print_records(&result, &input),
+            Format::Table => results.push(result),
+        }
+    }
+
+    if let Format::Table = format {
+        table::print_summary(&results);
+    }
+
+    Ok(())
+}
+
+fn bench_days(
+    days: &str,
+    part: Option<u8>,
+    input: Option<&Path>,
+    iterations: usize,
+    output: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<(), Error> {
+    let parts = select_parts(part)?;
+    let days = select_days(days, input)?;
+
+    let mut report = Report {
+        iterations,
+        days: Vec::new(),
+    };
+    for day in &days {
+        let input = get_input(day.number, input, days.len() == 1)?;
+        report
+            .days
+            .push(bench::bench(day, &input, &parts, iterations)?);
+    }
+
+    table::print_bench(&report);
+
+    if let Some(output) = output {
+        report.save(output)?;
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = report.regressions(&Report::load(baseline)?, threshold);
+        for regression in &regressions {
+            println!(
+                "REGRESSION day {} {}: median {} -> {} (+{:.1}%)",
+                regression.day,
+                regression.phase,
+                table::format_nanos(regression.baseline_ns),
+                table::format_nanos(regression.current_ns),
+                regression.percent()
+            );
+        }
+
+        if !regressions.is_empty() {
+            return Err(Error::new(format!(
+                "{} phases regressed by more than {}%",
+                regressions.len(),
+                threshold
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn select_parts(part: Option<u8>) -> Result<Vec<Part>, Error> {
+    match part {
+        Some(part) => Ok(vec![Part::parse(part)?]),
+        None => Ok(Part::all().to_vec()),
+    }
+}
+
+fn select_days(days: &str, input: Option<&Path>) -> Result<Vec<Day>, Error> {
+    let days = days::select(days)?;
+    if input.is_some() && days.len() > 1 {
+        return Err(Error::new(
+            "--input can only be used when running a single day".to_string(),
+        ));
+    }
+
+    Ok(days)
+}
+
+fn verify_days(days: &str, manifest: &Path) -> Result<(), Error> {
+    let days = days::select(days)?;
+    // Inputs in the manifest are relative to the manifest itself
+    let root = manifest.parent().map(PathBuf::from).unwrap_or_default();
+    let manifest = Manifest::load(manifest)?;
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for expected in &manifest.answers {
+        if !days.iter().any(|day| day.number == expected.day) {
+            continue;
+        }
+
+        let Some(checks) = verify::verify(expected, &root)? else {
+            println!(
+                "Day {:>2} {}: skipped, input not found",
+                expected.day,
+                expected.input.display()
+            );
+            skipped += 1;
+            continue;
+        };
+
+        for check in checks {
+            if check.is_correct() {
+                println!(
+                    "Day {:>2} part {} {}: ok ({})",
+                    expected.day,
+                    check.part,
+                    expected.input.display(),
+                    check.actual
+                );
+                passed += 1;
+            } else {
+                println!(
+                    "Day {:>2} part {} {}: MISMATCH expected {}, got {}",
+                    expected.day,
+                    check.part,
+                    expected.input.display(),
+                    check.expected,
+                    check.actual
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} skipped", passed, failed, skipped);
+    if failed > 0 {
+        return Err(Error::new(format!("{} answers did not match", failed)));
+    }
+
+    Ok(())
+}
+
+fn render_day(day: u8, input: Option<&Path>, color: ColorMode) -> Result<(), Error> {
+    let Some(day) = days::find(day) else {
+        return Err(Error::new(format!("Day {} is not registered", day)));
+    };
+    let input = get_input(day.number, input, true)?;
+    let Some(grid) = day.render(&input)? else {
+        return Err(Error::new(format!("Day {} has no renderer", day.number)));
+    };
+
+    let color = match color {
+        ColorMode::Auto => stdout_supports_color(),
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+    };
+    print!("{}", grid.to_ansi(color));
+
+    Ok(())
+}
+
+fn export_day(
+    day: u8,
+    output: &Path,
+    input: Option<&Path>,
+    scale: usize,
+    palette: &Palette,
+    sampling: Sampling,
+    delay: u16,
+) -> Result<(), Error> {
+    let Some(day) = days::find(day) else {
+        return Err(Error::new(format!("Day {} is not registered", day)));
+    };
+    let input = get_input(day.number, input, true)?;
+
+    match output.extension().and_then(|extension| extension.to_str()) {
+        Some("png") => {
+            let Some(grid) = day.render(&input)? else {
+                return Err(Error::new(format!("Day {} has no renderer", day.number)));
+            };
+            export::write_png(output, &grid, scale, palette)?;
+            println!("Wrote {}", output.display());
+        }
+        Some("gif") => {
+            let mut gif = export::GifWriter::new(output, scale, delay, palette);
+            let mut frames = Frames::new(sampling.every, sampling.max_frames, |frame| {
+                gif.write_frame(frame)
+            });
+            day.animate(&input, &mut frames)?;
+            let written = frames.finish()?;
+            gif.finish()?;
+            if written == 0 {
+                return Err(Error::new(format!("Day {} has no animation", day.number)));
+            }
+            println!("Wrote {} ({} frames)", output.display(), written);
+        }
+        _ => {
+            return Err(Error::new(format!(
+                "Don't know how to write {}, use .png or .gif",
+                output.display()
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+fn report_day(day: u8, input: Option<&Path>, format: ReportFormat) -> Result<(), Error> {
+    let Some(day) = days::find(day) else {
+        return Err(Error::new(format!("Day {} is not registered", day)));
+    };
+    let input = get_input(day.number, input, true)?;
+    let tables = day.report(&input)?;
+    if tables.is_empty() {
+        return Err(Error::new(format!("Day {} has no report", day.number)));
+    }
+
+    // A blank line between tables keeps them apart, except in JSON where
+    // every line is a record
+    let separator = match format {
+        ReportFormat::Json => "",
+        ReportFormat::Table | ReportFormat::Csv => "\n",
+    };
+    let tables: Vec<String> = tables.iter().map(|table| table.format(format)).collect();
+    print!("{}", tables.join(separator));
+
+    Ok(())
+}
+
+fn generate_day(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+) -> Result<(), Error> {
+    let Some(day) = days::find(day) else {
+        return Err(Error::new(format!("Day {} is not registered", day)));
+    };
+    let Some(input) = day.generate(seed, size) else {
+        return Err(Error::new(format!("Day {} has no generator", day.number)));
+    };
+
+    match output {
+        Some(path) => std::fs::write(path, input)
+            .map_err(|e| Error::new(format!("Could not write {}: {}", path.display(), e))),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn new_day(day: u8) -> Result<(), Error> {
+    for path in scaffold::new_day(Path::new("."), day)? {
+        println!("Wrote {}", path.display());
+    }
+    println!("Rebuild to run day {}", day);
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{input_path, Error};
+
+use crate::{days, verify::MANIFEST};
+
+const TEMPLATE_DIR: &str = "template";
+const TEMPLATE_NAME: &str = "template";
+const TEMPLATE_TYPE: &str = "Template";
+const EXAMPLES_DIR: &str = "examples";
+
+/// Creates crate `dayNN` from the template under `root`, registers it with the
+/// workspace and the runner, and adds an empty input plus an example entry in
+/// the answers manifest. Refuses to touch a day that already exists. Returns
+/// the files that were created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let name = format!("day{:02}", day);
+    let type_name = format!("Day{:02}", day);
+    let crate_dir = root.join(&name);
+
+    if days::find(day).is_some() {
+        return Err(Error::new(format!("Day {} is already registered", day)));
+    }
+    if crate_dir.exists() {
+        return Err(Error::new(format!(
+            "{} already exists",
+            crate_dir.display()
+        )));
+    }
+
+    // Work out every edit before writing anything, so a failure leaves the
+    // tree untouched
+    let template = root.join(TEMPLATE_DIR);
+    let cargo_toml = read(&template.join("Cargo.toml"))?.replace(
+        &format!("name = \"{}\"", TEMPLATE_NAME),
+        &format!("name = \"{}\"", name),
+    );
+    let lib_rs = read(&template.join("src").join("lib.rs"))?.replace(TEMPLATE_TYPE, &type_name);
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = insert_sorted(
+        &read(&workspace_path)?,
+        "    \"",
+        &format!("    \"{}\",", name),
+    )?;
+
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let runner = insert_sorted(
+        &read(&runner_path)?,
+        "day",
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+    )?;
+
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let days = read(&days_path)?;
+    let days = insert_sorted(&days, "use day", &format!("use {}::{};", name, type_name))?;
+    let days = insert_sorted(
+        &days,
+        "    Day::new::<Day",
+        &format!("    Day::new::<{}>({}),", type_name, day),
+    )?;
+
+    let manifest_path = root.join(MANIFEST);
+    let example = format!("{}/{:02}.txt", EXAMPLES_DIR, day);
+    let mut manifest = read(&manifest_path)?;
+    manifest += &format!(
+        "\n[[answers]]\nday = {}\ninput = \"{}\"\n# part1 = \"\"\n# part2 = \"\"\n",
+        day, example
+    );
+
+    let mut changed = Vec::new();
+    fs::create_dir_all(crate_dir.join("src"))?;
+    changed.push(write(&crate_dir.join("Cargo.toml"), &cargo_toml)?);
+    changed.push(write(&crate_dir.join("src").join("lib.rs"), &lib_rs)?);
+    changed.push(write(&workspace_path, &workspace)?);
+    changed.push(write(&runner_path, &runner)?);
+    changed.push(write(&days_path, &days)?);
+    changed.push(write(&manifest_path, &manifest)?);
+
+    // Never clobber inputs that are already there
+    for path in [root.join(input_path(day)), root.join(example)] {
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            changed.push(write(&path, "")?);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping that
+/// run in sorted order.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> Result<String, Error> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let matching: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect();
+
+    let Some(last) = matching.last() else {
+        return Err(Error::new(format!(
+            "Could not find where to insert '{}'",
+            line.trim()
+        )));
+    };
+
+    let mut index = last + 1;
+    for i in &matching {
+        if lines[*i] > line {
+            index = *i;
+            break;
+        }
+    }
+    lines.insert(index, line);
+
+    let mut result = lines.join("\n");
+    if contents.ends_with('\n') {
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("Could not read {}: {}", path.display(), e)))
+}
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf, Error> {
+    fs::write(path, contents)
+        .map_err(|e| Error::new(format!("Could not write {}: {}", path.display(), e)))?;
+
+    Ok(path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_sorted_keeps_the_run_in_order() {
+        let contents = "[deps]\nday01 = 1\nday03 = 3\n\n[other]\n";
+
+        assert_eq!(
+            insert_sorted(contents, "day", "day02 = 2").unwrap(),
+            "[deps]\nday01 = 1\nday02 = 2\nday03 = 3\n\n[other]\n"
+        );
+        assert_eq!(
+            insert_sorted(contents, "day", "day04 = 4").unwrap(),
+            "[deps]\nday01 = 1\nday03 = 3\nday04 = 4\n\n[other]\n"
+        );
+    }
+
+    #[test]
+    fn insert_sorted_needs_a_run_to_insert_into() {
+        let error = insert_sorted("[deps]\n", "day", "day01 = 1").unwrap_err();
+
+        assert!(error.to_string().contains("day01 = 1"), "{}", error);
+    }
+}
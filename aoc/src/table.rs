@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use aoc::bench::Report;
+use aoc_common::DayResult;
+
+pub fn print_summary(results: &[DayResult]) {
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>10}  {:>10}",
+        "Day", "Part", "Answer", "Parse", "Time"
+    );
+
+    let mut total = Duration::ZERO;
+    for result in results {
+        total += result.parse;
+        for part in &result.parts {
+            total += part.duration;
+            println!(
+                "{:>3}  {:>4}  {:<20}  {:>10}  {:>10}",
+                result.day,
+                part.part,
+                part.answer.to_string(),
+                format_duration(result.parse),
+                format_duration(part.duration)
+            );
+        }
+    }
+
+    println!("Total time: {}", format_duration(total));
+}
+
+pub fn print_bench(report: &Report) {
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+
+    for day in &report.days {
+        for phase in &day.phases {
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                day.day,
+                phase.phase,
+                format_nanos(phase.min_ns),
+                format_nanos(phase.median_ns),
+                format_nanos(phase.max_ns)
+            );
+        }
+    }
+
+    println!("Iterations: {}", report.iterations);
+}
+
+pub fn format_nanos(nanos: u64) -> String {
+    format_duration(Duration::from_nanos(nanos))
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
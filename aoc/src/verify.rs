@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{get_input, Error, Part};
+use serde::Deserialize;
+
+use crate::days;
+
+/// Default location of the expected-answers manifest, relative to the workspace root.
+pub const MANIFEST: &str = "answers.toml";
+
+#[derive(Deserialize)]
+pub struct Manifest {
+    pub answers: Vec<Expected>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("Could not read {}: {}", path.display(), e)))?;
+
+        toml::from_str(&contents)
+            .map_err(|e| Error::new(format!("Invalid manifest {}: {}", path.display(), e)))
+    }
+}
+
+/// The known answers for one day against one input file.
+#[derive(Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+pub struct PartCheck {
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl PartCheck {
+    pub fn is_correct(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Runs `expected.day` against its input, resolved relative to `root`, and
+/// compares each part that has a known answer. Returns `None` when the input
+/// file does not exist.
+pub fn verify(expected: &Expected, root: &Path) -> Result<Option<Vec<PartCheck>>, Error> {
+    let path = root.join(&expected.input);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let day = days::find(expected.day)
+        .ok_or_else(|| Error::new(format!("Day {} is not registered", expected.day)))?;
+    let input = get_input(day.number, Some(&path), false)?;
+
+    let parts: Vec<Part> = Part::all()
+        .into_iter()
+        .filter(|part| expected.answer(*part).is_some())
+        .collect();
+    let result = day.run(&input, &parts)?;
+
+    let mut checks = Vec::new();
+    for part in result.parts {
+        if let Some(answer) = expected.answer(part.part) {
+            checks.push(PartCheck {
+                part: part.part,
+                expected: answer.to_string(),
+                actual: part.answer.to_string(),
+            });
+        }
+    }
+
+    Ok(Some(checks))
+}
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use aoc::verify::{self, Manifest, MANIFEST};
+
+fn check(index: usize) {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let manifest = Manifest::load(&root.join(MANIFEST)).unwrap();
+    let expected = &manifest.answers[index];
+
+    match verify::verify(expected, &root).unwrap() {
+        Some(checks) => {
+            for check in checks {
+                assert!(
+                    check.is_correct(),
+                    "day {} part {} ({}): expected {}, got {}",
+                    expected.day,
+                    check.part,
+                    expected.input.display(),
+                    check.expected,
+                    check.actual
+                );
+            }
+        }
+        None => eprintln!("skipping {}: input not found", expected.input.display()),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
@@ -0,0 +1,33 @@
+use aoc::days;
+use aoc_common::Part;
+
+fn error(day: u8, input: &str) -> String {
+    days::find(day)
+        .unwrap()
+        .run(input, &Part::all())
+        .err()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn day01_rejects_negative_location_ids() {
+    let error = error(1, "3   4\n-5   -5\n");
+
+    assert!(error.contains("line 2, column 1"), "{}", error);
+}
+
+#[test]
+fn day01_points_at_the_extra_location_id() {
+    let error = error(1, "3   4\n3   4   5\n");
+
+    assert!(error.contains("expected only 2 location IDs"), "{}", error);
+    assert!(error.contains("line 2, column 9"), "{}", error);
+}
+
+#[test]
+fn day06_fails_when_the_guard_never_leaves() {
+    let error = error(6, ".#..\n...#\n#^..\n..#.\n");
+
+    assert!(error.contains("never leaves the map"), "{}", error);
+}
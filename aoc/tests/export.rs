@@ -0,0 +1,65 @@
+use std::{env, fs::File, path::Path};
+
+use aoc::{
+    days,
+    export::{GifWriter, Palette},
+};
+use aoc_common::Frames;
+
+fn export(day: u8, input: &str, every: usize, max_frames: usize) -> (usize, usize) {
+    let path = env::temp_dir().join(format!(
+        "aoc-export-{}-{}-{}.gif",
+        std::process::id(),
+        day,
+        max_frames
+    ));
+    let palette = Palette::xterm();
+
+    let mut gif = GifWriter::new(&path, 2, 5, &palette);
+    let mut frames = Frames::new(every, max_frames, |frame| gif.write_frame(frame));
+    days::find(day)
+        .unwrap()
+        .animate(input, &mut frames)
+        .unwrap();
+    let written = frames.finish().unwrap();
+    gif.finish().unwrap();
+
+    let decoded = decoded_frames(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    (written, decoded)
+}
+
+fn decoded_frames(path: &Path) -> usize {
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+    let mut decoder = options.read_info(File::open(path).unwrap()).unwrap();
+
+    let mut count = 0;
+    while decoder.read_next_frame().unwrap().is_some() {
+        count += 1;
+    }
+
+    count
+}
+
+#[test]
+fn every_kept_frame_is_written() {
+    let input = "..#.\n#..#\n.^..\n....\n";
+
+    // The starting state and two steps up to the top edge
+    assert_eq!(export(6, input, 1, 1000), (3, 3));
+}
+
+#[test]
+fn frames_are_sampled_and_capped() {
+    let input = "2333133121414131402";
+
+    let (all, _) = export(9, input, 1, 1000);
+    assert!(all > 4, "{}", all);
+    assert_eq!(
+        export(9, input, 2, 1000),
+        (all.div_ceil(2), all.div_ceil(2))
+    );
+    assert_eq!(export(9, input, 1, 4), (4, 4));
+}
@@ -0,0 +1,13 @@
+use aoc::days;
+use aoc_common::Part;
+
+#[test]
+fn day06_guard_always_leaves_generated_maps() {
+    let day = days::find(6).unwrap();
+
+    // Seeds 2, 12, 20 and 26 used to trap the guard on a full-size map
+    for seed in 0..40 {
+        let input = day.generate(seed, None).unwrap();
+        assert!(day.run(&input, &[Part::One]).is_ok(), "seed {}", seed);
+    }
+}
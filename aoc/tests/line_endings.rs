@@ -0,0 +1,82 @@
+use std::{fs, path::PathBuf};
+
+use aoc::days;
+use aoc_common::{Answer, Grid, Part};
+
+fn example(day: u8) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("examples")
+        .join(format!("{:02}.txt", day));
+
+    fs::read_to_string(path).unwrap()
+}
+
+fn answers(day: u8, input: &str) -> Result<Vec<Answer>, String> {
+    let result = days::find(day)
+        .unwrap()
+        .run(input, &Part::all())
+        .map_err(|e| e.to_string())?;
+
+    Ok(result.parts.into_iter().map(|part| part.answer).collect())
+}
+
+#[test]
+fn windows_line_endings_give_the_same_answers() {
+    for day in days::DAYS {
+        let input = example(day.number);
+        let windows = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"));
+
+        assert_eq!(
+            answers(day.number, &windows),
+            answers(day.number, &input),
+            "day {}",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn ragged_maps_are_rejected() {
+    for day in [4, 6, 8, 10] {
+        let input = example(day);
+        let mut lines: Vec<&str> = input.lines().collect();
+        lines[1] = &lines[1][1..];
+
+        let error = answers(day, &lines.join("\n")).unwrap_err();
+        assert!(error.contains("line 2"), "day {}: {}", day, error);
+    }
+}
+
+#[test]
+fn blank_lines_inside_maps_are_rejected() {
+    for day in [4, 6, 8, 10] {
+        let input = example(day).replacen('\n', "\n\n", 1);
+
+        let error = answers(day, &input).unwrap_err();
+        assert!(error.contains("blank line"), "day {}: {}", day, error);
+    }
+}
+
+#[test]
+fn columns_are_counted_in_characters() {
+    let error = answers(8, "..é.\n..x.\n").unwrap_err();
+
+    assert!(error.contains("line 1, column 3"), "{}", error);
+}
+
+#[test]
+fn multibyte_cells_take_up_one_column() {
+    let grid = Grid::parse("é#\n..").unwrap();
+
+    assert_eq!(grid.width(), 2);
+    assert_eq!(grid.get((1, 0)), Some(&'#'));
+}
+
+#[test]
+fn multibyte_cells_do_not_shift_the_cells_after_them() {
+    // XMAS runs down the second column, after an accented cell on every row
+    let answers = answers(4, "éX\néM\néA\néS\n").unwrap();
+
+    assert_eq!(answers, [Answer::Number(1), Answer::Number(0)]);
+}
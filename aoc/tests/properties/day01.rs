@@ -0,0 +1,90 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use ::day01::Day01;
+use aoc_common::Solution;
+use proptest::{collection::vec, prelude::*};
+
+use crate::answers;
+
+proptest! {
+    #[test]
+    fn matches_reference(pairs in vec((0..100u32, 0..100u32), 1..50)) {
+        let input: String = pairs
+            .iter()
+            .map(|(first, second)| format!("{}   {}\n", first, second))
+            .collect();
+        let (first, second): (Vec<u32>, Vec<u32>) = pairs.into_iter().unzip();
+
+        let (part1, part2) = answers::<Day01>(&input);
+        prop_assert_eq!(part1.clone(), distance(first.clone(), second.clone()).into());
+        prop_assert_eq!(part2.clone(), similarity(&first, &second).into());
+
+        let lists = Day01::parse(&input).unwrap();
+        let report = lists.report();
+        prop_assert_eq!(part1, report.distance.into());
+        prop_assert_eq!(part2, report.similarity.into());
+        let totals = totals(&first, &second);
+        for pair in report.most_frequent.windows(2) {
+            prop_assert!((Reverse(pair[0].1), pair[0].0) < (Reverse(pair[1].1), pair[1].0));
+        }
+        for (id, total) in &report.most_frequent {
+            prop_assert_eq!(totals[id], *total);
+        }
+        // Nothing left out is seen more often than the least of those kept
+        if let Some((_, least)) = report.most_frequent.last() {
+            let kept = report.most_frequent.len();
+            // Five are kept, or every ID if there are fewer
+            prop_assert!(kept == 5 || kept == totals.len());
+            let more = totals.values().filter(|total| *total > least).count();
+            prop_assert!(more < kept);
+        }
+
+        let first: HashSet<u32> = first.into_iter().collect();
+        let second: HashSet<u32> = second.into_iter().collect();
+        prop_assert_eq!(report.shared, first.intersection(&second).count());
+        prop_assert_eq!(report.only_first, first.difference(&second).count());
+        prop_assert_eq!(report.only_second, second.difference(&first).count());
+    }
+}
+
+/// Pairs up the smallest IDs left in each list until they run out.
+fn distance(mut first: Vec<u32>, mut second: Vec<u32>) -> u64 {
+    let mut distance = 0;
+    while let (Some(first), Some(second)) = (take_smallest(&mut first), take_smallest(&mut second))
+    {
+        distance += first.abs_diff(second) as u64;
+    }
+
+    distance
+}
+
+fn take_smallest(list: &mut Vec<u32>) -> Option<u32> {
+    let (index, _) = list.iter().enumerate().min_by_key(|(_, id)| **id)?;
+
+    Some(list.swap_remove(index))
+}
+
+/// How often each ID appears across both lists.
+fn totals(first: &[u32], second: &[u32]) -> HashMap<u32, u64> {
+    let mut totals: HashMap<u32, u64> = HashMap::new();
+    for id in first.iter().chain(second) {
+        *totals.entry(*id).or_default() += 1;
+    }
+
+    totals
+}
+
+fn similarity(first: &[u32], second: &[u32]) -> u64 {
+    let mut counts: HashMap<u32, u64> = HashMap::new();
+    for id in second {
+        *counts.entry(*id).or_default() += 1;
+    }
+
+    first
+        .iter()
+        .map(|id| *id as u64 * counts.get(id).copied().unwrap_or_default())
+        .sum()
+}
@@ -0,0 +1,109 @@
+use ::day02::{Day02, Direction, SafetyPolicy};
+use proptest::{collection::vec, prelude::*};
+
+use crate::answers;
+
+proptest! {
+    #[test]
+    fn matches_reference(reports in vec(vec(1..12i32, 1..8), 1..30)) {
+        let input: String = reports
+            .iter()
+            .map(|levels| {
+                let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect();
+        let safe = reports.iter().filter(|levels| is_safe(levels)).count();
+        let dampened = reports.iter().filter(|levels| is_safe_dampened(levels)).count();
+
+        let (part1, part2) = answers::<Day02>(&input);
+        prop_assert_eq!(part1, safe.into());
+        prop_assert_eq!(part2, dampened.into());
+    }
+
+    #[test]
+    fn policy_matches_reference(
+        levels in vec(1..12i32, 1..10),
+        (min, max) in (0..3u32, 0..4u32).prop_map(|(min, extra)| (min, min + extra)),
+        direction in prop_oneof![
+            Just(Direction::Any),
+            Just(Direction::Increasing),
+            Just(Direction::Decreasing),
+        ],
+        k in 0..4usize,
+    ) {
+        let policy = SafetyPolicy::new()
+            .steps(min, max)
+            .unwrap()
+            .direction(direction)
+            .max_removals(k);
+        let fewest = (0..=k.min(levels.len())).find(|removals| {
+            subsets(levels.len(), *removals).any(|removed| {
+                let kept: Vec<i32> = levels
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !removed.contains(index))
+                    .map(|(_, level)| *level)
+                    .collect();
+                follows(&kept, min, max, direction)
+            })
+        });
+
+        let assessment = policy.assess(&levels);
+        prop_assert_eq!(assessment.violation.is_none(), fewest == Some(0));
+
+        let removed = assessment.removed;
+        prop_assert_eq!(removed.as_ref().map(Vec::len), fewest);
+        if let Some(removed) = removed {
+            let kept: Vec<i32> = levels
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !removed.contains(index))
+                .map(|(_, level)| *level)
+                .collect();
+            prop_assert!(follows(&kept, min, max, direction));
+        }
+    }
+}
+
+/// Every step is up by 1 to 3, or every step is down by 1 to 3.
+fn is_safe(levels: &[i32]) -> bool {
+    let steps: Vec<i32> = levels.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+    steps.iter().all(|step| (1..=3).contains(step))
+        || steps.iter().all(|step| (-3..=-1).contains(step))
+}
+
+fn is_safe_dampened(levels: &[i32]) -> bool {
+    is_safe(levels)
+        || (0..levels.len()).any(|skipped| {
+            let rest: Vec<i32> = levels
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != skipped)
+                .map(|(_, level)| *level)
+                .collect();
+            is_safe(&rest)
+        })
+}
+
+/// Every step is within `min..=max` in the required direction.
+fn follows(levels: &[i32], min: u32, max: u32, direction: Direction) -> bool {
+    let (min, max) = (min as i32, max as i32);
+    let steps: Vec<i32> = levels.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let up = steps.iter().all(|step| (min..=max).contains(step));
+    let down = steps.iter().all(|step| (min..=max).contains(&-step));
+
+    match direction {
+        Direction::Any => up || down,
+        Direction::Increasing => up,
+        Direction::Decreasing => down,
+    }
+}
+
+/// Every way of choosing `size` of the indices below `n`.
+fn subsets(n: usize, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    (0u32..1 << n)
+        .filter(move |mask| mask.count_ones() as usize == size)
+        .map(move |mask| (0..n).filter(|index| mask & (1 << index) != 0).collect())
+}
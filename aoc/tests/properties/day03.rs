@@ -0,0 +1,59 @@
+use ::day03::Day03;
+use proptest::{collection::vec, prelude::*, sample::select};
+
+use crate::answers;
+
+/// Pieces of real and broken instructions, so the memory has plenty of both.
+const PIECES: &[&str] = &[
+    "mul(", "mul", "(", ")", ",", "do()", "don't()", "do", "n't", "1", "23", "456", "7890", " ",
+    "x", "\n",
+];
+
+proptest! {
+    #[test]
+    fn matches_reference(pieces in vec(select(PIECES), 0..40)) {
+        let memory = pieces.concat();
+
+        let (part1, part2) = answers::<Day03>(&memory);
+        prop_assert_eq!(part1, scan(&memory, false).into());
+        prop_assert_eq!(part2, scan(&memory, true).into());
+    }
+}
+
+/// Tries to read an instruction at every position in turn, only obeying
+/// `do()` and `don't()` if `conditionals` is set.
+fn scan(memory: &str, conditionals: bool) -> u64 {
+    let mut enabled = true;
+    let mut total = 0;
+    for start in 0..memory.len() {
+        let rest = &memory[start..];
+        if rest.starts_with("do()") {
+            enabled = true;
+        } else if rest.starts_with("don't()") {
+            enabled = false;
+        } else if let Some(product) = mul(rest) {
+            if enabled || !conditionals {
+                total += product;
+            }
+        }
+    }
+
+    total
+}
+
+fn mul(text: &str) -> Option<u64> {
+    let (first, rest) = number(text.strip_prefix("mul(")?)?;
+    let (second, rest) = number(rest.strip_prefix(',')?)?;
+
+    rest.starts_with(')').then_some(first * second)
+}
+
+/// A number of one to three digits at the start of `text`, and what follows.
+fn number(text: &str) -> Option<(u64, &str)> {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    if !(1..=3).contains(&digits) {
+        return None;
+    }
+
+    Some((text[..digits].parse().ok()?, &text[digits..]))
+}
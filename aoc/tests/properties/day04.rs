@@ -0,0 +1,67 @@
+use ::day04::Day04;
+use proptest::{prelude::*, sample::select};
+
+use crate::{answers, grid, grid_text};
+
+proptest! {
+    #[test]
+    fn matches_reference(rows in grid(select(vec!['X', 'M', 'A', 'S']), 8)) {
+        let xmases: usize = lines(&rows)
+            .iter()
+            .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+            .sum();
+
+        let (part1, part2) = answers::<Day04>(&grid_text(&rows));
+        prop_assert_eq!(part1, xmases.into());
+        prop_assert_eq!(part2, crosses(&rows).into());
+    }
+}
+
+/// Every row, column and diagonal of the grid, read from one edge to the
+/// other.
+fn lines(rows: &[Vec<char>]) -> Vec<String> {
+    let height = rows.len() as isize;
+    let width = rows[0].len() as isize;
+    let inside = |x: isize, y: isize| x >= 0 && x < width && y >= 0 && y < height;
+
+    let mut lines = Vec::new();
+    for (dx, dy) in [(1, 0), (0, 1), (1, 1), (1, -1)] {
+        for y in 0..height {
+            for x in 0..width {
+                // Only start from the edge the line comes in from
+                if inside(x - dx, y - dy) {
+                    continue;
+                }
+
+                let mut line = String::new();
+                let (mut x, mut y) = (x, y);
+                while inside(x, y) {
+                    line.push(rows[y as usize][x as usize]);
+                    x += dx;
+                    y += dy;
+                }
+                lines.push(line);
+            }
+        }
+    }
+
+    lines
+}
+
+/// The 3 by 3 squares with MAS written along both diagonals, either way round.
+fn crosses(rows: &[Vec<char>]) -> usize {
+    let is_mas = |diagonal: [char; 3]| diagonal == ['M', 'A', 'S'] || diagonal == ['S', 'A', 'M'];
+
+    let mut crosses = 0;
+    for y in 0..rows.len().saturating_sub(2) {
+        for x in 0..rows[0].len().saturating_sub(2) {
+            let down = [rows[y][x], rows[y + 1][x + 1], rows[y + 2][x + 2]];
+            let up = [rows[y + 2][x], rows[y + 1][x + 1], rows[y][x + 2]];
+            if is_mas(down) && is_mas(up) {
+                crosses += 1;
+            }
+        }
+    }
+
+    crosses
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use ::day05::Day05;
+use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+use crate::answers;
+
+/// A correct order for some pages, and updates made up of some of those pages
+/// in any order.
+fn manual() -> impl Strategy<Value = (Vec<u64>, Vec<Vec<u64>>)> {
+    subsequence((10..100).collect::<Vec<u64>>(), 2..10)
+        .prop_shuffle()
+        .prop_flat_map(|order| {
+            let update = subsequence(order.clone(), 1..=order.len())
+                .prop_shuffle()
+                .prop_map(|mut pages| {
+                    // An odd number of pages so there is a middle one
+                    if pages.len().is_multiple_of(2) {
+                        pages.pop();
+                    }
+                    pages
+                });
+
+            (Just(order), vec(update, 1..10))
+        })
+}
+
+proptest! {
+    #[test]
+    fn matches_reference((order, updates) in manual()) {
+        // A rule between every pair of pages, so each update has exactly one
+        // correct order
+        let mut rules = HashSet::new();
+        let mut input = String::new();
+        for (i, before) in order.iter().enumerate() {
+            for after in &order[i + 1..] {
+                rules.insert((*before, *after));
+                input += &format!("{}|{}\n", before, after);
+            }
+        }
+        input.push('\n');
+        for update in &updates {
+            let pages: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+            input += &(pages.join(",") + "\n");
+        }
+
+        let mut ordered = 0;
+        let mut fixed = 0;
+        for update in &updates {
+            let sorted = sort(update, &rules);
+            let middle = sorted[sorted.len() / 2];
+            if sorted == *update {
+                ordered += middle;
+            } else {
+                fixed += middle;
+            }
+        }
+
+        let (part1, part2) = answers::<Day05>(&input);
+        prop_assert_eq!(part1, ordered.into());
+        prop_assert_eq!(part2, fixed.into());
+    }
+}
+
+/// Bubble sorts the pages, swapping any neighbours a rule says are the wrong
+/// way round.
+fn sort(update: &[u64], rules: &HashSet<(u64, u64)>) -> Vec<u64> {
+    let mut pages = update.to_vec();
+    let mut swapped = true;
+    while swapped {
+        swapped = false;
+        for i in 1..pages.len() {
+            if rules.contains(&(pages[i], pages[i - 1])) {
+                pages.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+    }
+
+    pages
+}
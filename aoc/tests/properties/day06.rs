@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use ::day06::Day06;
+use proptest::{prelude::*, sample::Index};
+
+use crate::{answers, grid, grid_text};
+
+type Position = (isize, isize);
+
+proptest! {
+    #[test]
+    fn matches_reference(
+        mut rows in grid(prop_oneof![4 => Just('.'), 1 => Just('#')], 8),
+        guard in any::<Index>(),
+    ) {
+        let width = rows[0].len();
+        let guard = guard.index(width * rows.len());
+        let start = ((guard % width) as isize, (guard / width) as isize);
+        rows[guard / width][guard % width] = '^';
+
+        // The puzzle promises the guard leaves the original map
+        let visited = patrol(&rows, start, None);
+        prop_assume!(visited.is_some());
+
+        let mut loops: usize = 0;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, space) in row.iter().enumerate() {
+                let object = (x as isize, y as isize);
+                if *space == '.' && patrol(&rows, start, Some(object)).is_none() {
+                    loops += 1;
+                }
+            }
+        }
+
+        let (part1, part2) = answers::<Day06>(&grid_text(&rows));
+        prop_assert_eq!(part1, visited.unwrap_or_default().len().into());
+        prop_assert_eq!(part2, loops.into());
+    }
+}
+
+/// The spaces the guard visits before leaving the map with `object` added to
+/// it, or `None` if they end up going round in circles.
+fn patrol(
+    rows: &[Vec<char>],
+    start: Position,
+    object: Option<Position>,
+) -> Option<HashSet<Position>> {
+    let inside = |(x, y): Position| {
+        x >= 0 && y >= 0 && (y as usize) < rows.len() && (x as usize) < rows[0].len()
+    };
+    let blocked = |(x, y): Position| object == Some((x, y)) || rows[y as usize][x as usize] == '#';
+
+    let mut position = start;
+    let mut heading = (0, -1);
+    let mut states = HashSet::new();
+    while states.insert((position, heading)) {
+        let next = (position.0 + heading.0, position.1 + heading.1);
+        if !inside(next) {
+            return Some(states.into_iter().map(|(position, _)| position).collect());
+        }
+
+        if blocked(next) {
+            heading = (-heading.1, heading.0);
+        } else {
+            position = next;
+        }
+    }
+
+    None
+}
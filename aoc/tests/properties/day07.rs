@@ -0,0 +1,63 @@
+use ::day07::Day07;
+use proptest::{collection::vec, prelude::*};
+
+use crate::answers;
+
+/// Operands combined with random operators, and a total that is either what
+/// they came to or a little more.
+fn equation() -> impl Strategy<Value = (u64, Vec<u64>)> {
+    (vec(1..100u64, 1..=5), vec(0..3usize, 4), 0..3u64).prop_map(|(operands, operators, extra)| {
+        let mut total = operands[0];
+        for (operand, operator) in operands[1..].iter().zip(operators) {
+            total = apply(operator, total, *operand);
+        }
+
+        (total + extra, operands)
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(equations in vec(equation(), 1..20)) {
+        let input: String = equations
+            .iter()
+            .map(|(total, operands)| {
+                let operands: Vec<String> = operands.iter().map(|operand| operand.to_string()).collect();
+                format!("{}: {}\n", total, operands.join(" "))
+            })
+            .collect();
+        let calibration = |operators| -> u64 {
+            equations
+                .iter()
+                .filter(|(total, operands)| results(operands, operators).contains(total))
+                .map(|(total, _)| total)
+                .sum()
+        };
+
+        let (part1, part2) = answers::<Day07>(&input);
+        prop_assert_eq!(part1, calibration(2).into());
+        prop_assert_eq!(part2, calibration(3).into());
+    }
+}
+
+/// Every total the operands can come to using the first `operators` of add,
+/// multiply and concatenate.
+fn results(operands: &[u64], operators: usize) -> Vec<u64> {
+    let mut totals = vec![operands[0]];
+    for operand in &operands[1..] {
+        totals = totals
+            .iter()
+            .flat_map(|total| (0..operators).map(move |operator| apply(operator, *total, *operand)))
+            .collect();
+    }
+
+    totals
+}
+
+fn apply(operator: usize, first: u64, second: u64) -> u64 {
+    match operator {
+        0 => first + second,
+        1 => first * second,
+        _ => format!("{}{}", first, second).parse().unwrap(),
+    }
+}
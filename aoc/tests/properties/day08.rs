@@ -0,0 +1,54 @@
+use ::day08::Day08;
+use proptest::prelude::*;
+
+use crate::{answers, grid, grid_text};
+
+proptest! {
+    #[test]
+    fn matches_reference(
+        rows in grid(prop_oneof![12 => Just('.'), 1 => Just('a'), 1 => Just('A'), 1 => Just('0')], 10),
+    ) {
+        let (part1, part2) = answers::<Day08>(&grid_text(&rows));
+        prop_assert_eq!(part1, antinodes(&rows, false).into());
+        prop_assert_eq!(part2, antinodes(&rows, true).into());
+    }
+}
+
+/// Checks every location against every pair of antennas on the same
+/// frequency. Without harmonics a location counts when it is in line with the
+/// pair and twice as far from one as the other, beyond the nearer one. With
+/// them it only has to be in line.
+fn antinodes(rows: &[Vec<char>], harmonics: bool) -> usize {
+    let mut antennas = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, frequency) in row.iter().enumerate() {
+            if *frequency != '.' {
+                antennas.push((x as isize, y as isize, *frequency));
+            }
+        }
+    }
+
+    let mut antinodes = 0;
+    for y in 0..rows.len() as isize {
+        for x in 0..rows[0].len() as isize {
+            let is_antinode = antennas.iter().any(|&(ax, ay, first)| {
+                antennas.iter().any(|&(bx, by, second)| {
+                    if first != second || (ax, ay) == (bx, by) {
+                        return false;
+                    }
+
+                    if harmonics {
+                        (bx - ax) * (y - ay) == (by - ay) * (x - ax)
+                    } else {
+                        (x - ax, y - ay) == (2 * (x - bx), 2 * (y - by))
+                    }
+                })
+            });
+            if is_antinode {
+                antinodes += 1;
+            }
+        }
+    }
+
+    antinodes
+}
@@ -0,0 +1,75 @@
+use ::day09::Day09;
+use proptest::{collection::vec, prelude::*};
+
+use crate::answers;
+
+type Blocks = Vec<Option<u64>>;
+
+proptest! {
+    #[test]
+    fn matches_reference(lengths in vec((0..10usize, 0..10usize), 1..20)) {
+        let mut disk_map = String::new();
+        let mut blocks = Vec::new();
+        for (id, (file, free)) in lengths.iter().enumerate() {
+            disk_map += &file.to_string();
+            blocks.extend(std::iter::repeat_n(Some(id as u64), *file));
+            // The map ends with a file
+            if id + 1 < lengths.len() {
+                disk_map += &free.to_string();
+                blocks.extend(std::iter::repeat_n(None, *free));
+            }
+        }
+        disk_map.push('\n');
+
+        let (part1, part2) = answers::<Day09>(&disk_map);
+        prop_assert_eq!(part1, checksum(&compact(blocks.clone())).into());
+        prop_assert_eq!(part2, checksum(&defrag(blocks, lengths.len())).into());
+    }
+}
+
+/// Moves the last file block into the first free block until there are no
+/// gaps left.
+fn compact(mut blocks: Blocks) -> Blocks {
+    while let (Some(free), Some(last)) = (
+        blocks.iter().position(Option::is_none),
+        blocks.iter().rposition(Option::is_some),
+    ) {
+        if last < free {
+            break;
+        }
+        blocks.swap(free, last);
+    }
+
+    blocks
+}
+
+/// Moves each file, highest ID first, into the leftmost free span to its left
+/// that fits it.
+fn defrag(mut blocks: Blocks, files: usize) -> Blocks {
+    for id in (0..files as u64).rev() {
+        // Files of length zero have no blocks to move
+        let Some(start) = blocks.iter().position(|block| *block == Some(id)) else {
+            continue;
+        };
+        let length = blocks.iter().filter(|block| **block == Some(id)).count();
+        let free = (0..start).find(|&free| {
+            free + length <= start && blocks[free..free + length].iter().all(Option::is_none)
+        });
+
+        if let Some(free) = free {
+            for offset in 0..length {
+                blocks.swap(free + offset, start + offset);
+            }
+        }
+    }
+
+    blocks
+}
+
+fn checksum(blocks: &[Option<u64>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, block)| index as u64 * block.unwrap_or_default())
+        .sum()
+}
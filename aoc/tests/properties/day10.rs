@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use ::day10::Day10;
+use proptest::prelude::*;
+
+use crate::{answers, grid, grid_text};
+
+type Location = (usize, usize);
+
+proptest! {
+    #[test]
+    fn matches_reference(bumps in grid(0..3u8, 12)) {
+        // Rising towards the bottom right makes for plenty of trails
+        let rows: Vec<Vec<u8>> = bumps
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, bump)| ((x + y) as u8 + bump) % 10)
+                    .collect()
+            })
+            .collect();
+        let text: Vec<Vec<char>> = rows
+            .iter()
+            .map(|row| row.iter().map(|height| char::from(b'0' + height)).collect())
+            .collect();
+
+        let mut peaks = 0;
+        let mut trails = 0;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, height) in row.iter().enumerate() {
+                if *height == 0 {
+                    let ends = trail_ends(&rows, (x, y));
+                    peaks += ends.iter().collect::<HashSet<_>>().len();
+                    trails += ends.len();
+                }
+            }
+        }
+
+        let (part1, part2) = answers::<Day10>(&grid_text(&text));
+        prop_assert_eq!(part1, peaks.into());
+        prop_assert_eq!(part2, trails.into());
+    }
+}
+
+/// Follows every trail up from `location`, returning where each one ends.
+fn trail_ends(rows: &[Vec<u8>], (x, y): Location) -> Vec<Location> {
+    let height = rows[y][x];
+    if height == 9 {
+        return vec![(x, y)];
+    }
+
+    let neighbours = [
+        (x.wrapping_sub(1), y),
+        (x + 1, y),
+        (x, y.wrapping_sub(1)),
+        (x, y + 1),
+    ];
+
+    neighbours
+        .into_iter()
+        .filter(|&(x, y)| rows.get(y).and_then(|row| row.get(x)) == Some(&(height + 1)))
+        .flat_map(|next| trail_ends(rows, next))
+        .collect()
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use ::day11::Day11;
+use proptest::{collection::vec, prelude::*};
+
+use crate::answers;
+
+proptest! {
+    // Blinking 25 times one stone at a time is slow, so try fewer inputs
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn matches_reference(stones in vec(0..10_000u64, 1..5)) {
+        let numbers: Vec<String> = stones.iter().map(|stone| stone.to_string()).collect();
+
+        let (part1, part2) = answers::<Day11>(&(numbers.join(" ") + "\n"));
+        prop_assert_eq!(part1, count(&stones, 25).into());
+        prop_assert_eq!(part2, count(&stones, 75).into());
+    }
+}
+
+/// Blinks one step at a time, keeping how many stones have each number rather
+/// than every stone.
+fn count(stones: &[u64], blinks: usize) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for stone in stones {
+        *counts.entry(*stone).or_default() += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next: HashMap<u64, u64> = HashMap::new();
+        for (stone, count) in counts {
+            for stone in blink(stone) {
+                *next.entry(stone).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+
+    counts.values().sum()
+}
+
+fn blink(stone: u64) -> Vec<u64> {
+    let digits = stone.to_string();
+    if stone == 0 {
+        vec![1]
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        vec![left.parse().unwrap(), right.parse().unwrap()]
+    } else {
+        vec![stone * 2024]
+    }
+}
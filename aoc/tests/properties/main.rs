@@ -0,0 +1,40 @@
+// Checks every day's solver against a slow but obviously correct reference
+// on random inputs. Proptest shrinks any input that disagrees, so a failure
+// reports the smallest counterexample it could find.
+
+use aoc_common::{Answer, Solution};
+use proptest::{collection::vec, prelude::*};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+
+/// Both parts' answers for `input`, panicking if it fails to parse or solve.
+fn answers<S: Solution>(input: &str) -> (Answer, Answer) {
+    let model = S::parse(input).unwrap();
+
+    (S::part1(&model).unwrap(), S::part2(&model).unwrap())
+}
+
+/// A rectangular grid between 1 and `max` cells on each side.
+fn grid<S>(cell: S, max: usize) -> impl Strategy<Value = Vec<Vec<S::Value>>>
+where
+    S: Strategy + Clone,
+{
+    (1..=max, 1..=max).prop_flat_map(move |(width, height)| vec(vec(cell.clone(), width), height))
+}
+
+/// The grid as the parsers read it, one row per line.
+fn grid_text(rows: &[Vec<char>]) -> String {
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
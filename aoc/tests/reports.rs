@@ -0,0 +1,142 @@
+use aoc::days;
+use aoc_common::ReportFormat;
+use day02::SafetyPolicy;
+use serde_json::json;
+
+#[test]
+fn day01_compares_every_pair_of_lists() {
+    let input = "3 4 1\n4 3 2\n2 5 3\n1 3 3\n3 9 9\n3 3 1\n";
+    let tables = days::find(1).unwrap().report(input).unwrap();
+    let csv: Vec<String> = tables
+        .iter()
+        .map(|table| table.format(ReportFormat::Csv))
+        .collect();
+
+    assert_eq!(
+        csv,
+        [
+            "distance,1,2,3\n1,0,11,7\n2,11,0,8\n3,7,8,0\n",
+            "similarity,1,2,3\n1,34,31,22\n2,31,45,27\n3,22,27,27\n",
+            "statistic,value\nshared IDs,2\nIDs only in list 1,2\nIDs only in list 2,2\n\
+             most frequent IDs,\"3 x6, 4 x2, 1 x1, 2 x1, 5 x1\"\n",
+        ]
+    );
+}
+
+#[test]
+fn day01_lists_must_all_be_the_same_length() {
+    let error = days::find(1).unwrap().report("1 2 3\n1 2\n").unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("expected 3 location IDs, found 2"));
+}
+
+#[test]
+fn day02_explains_how_a_report_was_made_safe() {
+    let assessment = SafetyPolicy::new().max_removals(1).assess(&[1, 3, 2, 4, 5]);
+
+    assert_eq!(
+        assessment.to_string(),
+        "1 (3) [2] 4 5: safe after removing index 1; index 2 steps by -1, against the direction"
+    );
+    assert_eq!(
+        serde_json::to_value(&assessment).unwrap(),
+        json!({
+            "levels": [1, 3, 2, 4, 5],
+            "violation": { "index": 2, "kind": "direction", "delta": -1 },
+            "removed": [1],
+        })
+    );
+}
+
+#[test]
+fn day02_explains_why_a_report_is_unsafe() {
+    let assessment = SafetyPolicy::new().max_removals(1).assess(&[1, 2, 7, 8, 9]);
+
+    assert_eq!(
+        assessment.to_string(),
+        "1 2 [7] 8 9: unsafe; index 2 steps by +5, outside the allowed step size"
+    );
+    assert_eq!(
+        serde_json::to_value(&assessment).unwrap(),
+        json!({
+            "levels": [1, 2, 7, 8, 9],
+            "violation": { "index": 2, "kind": "step_size", "delta": 5 },
+            "removed": null,
+        })
+    );
+}
+
+#[test]
+fn day02_names_every_removed_level() {
+    let assessment = SafetyPolicy::new()
+        .max_removals(2)
+        .assess(&[1, 9, 2, 9, 3, 4]);
+
+    assert_eq!(
+        assessment.to_string(),
+        "1 [(9)] 2 (9) 3 4: safe after removing indices 1, 3; index 1 steps by +8, outside the allowed step size"
+    );
+}
+
+#[test]
+fn day02_reports_one_assessment_per_json_line() {
+    let input = "7 6 4 2 1\n1 3 2 4 5\n1 2 7 8 9\n";
+    let tables = days::find(2).unwrap().report(input).unwrap();
+    let json: String = tables
+        .iter()
+        .map(|table| table.format(ReportFormat::Json))
+        .collect();
+
+    let lines: Vec<serde_json::Value> = json
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let policy = SafetyPolicy::new().max_removals(1);
+    assert_eq!(
+        lines,
+        [
+            serde_json::to_value(policy.assess(&[7, 6, 4, 2, 1])).unwrap(),
+            serde_json::to_value(policy.assess(&[1, 3, 2, 4, 5])).unwrap(),
+            serde_json::to_value(policy.assess(&[1, 2, 7, 8, 9])).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn json_rows_without_a_record_are_keyed_by_the_header() {
+    let tables = days::find(1).unwrap().report("3 4\n4 3\n").unwrap();
+
+    assert_eq!(
+        tables[0].format(ReportFormat::Json),
+        "{\"distance\":\"1\",\"1\":\"0\",\"2\":\"0\"}\n{\"distance\":\"2\",\"1\":\"0\",\"2\":\"0\"}\n"
+    );
+}
+
+#[test]
+fn day02_steps_between_extreme_levels_do_not_overflow() {
+    let assessment = SafetyPolicy::new()
+        .max_removals(1)
+        .assess(&[i32::MAX, i32::MIN]);
+
+    assert!(assessment.is_safe());
+    assert_eq!(
+        assessment.violation.map(|violation| violation.delta),
+        Some(i64::from(i32::MIN) - i64::from(i32::MAX))
+    );
+}
+
+#[test]
+fn day02_step_ranges_must_not_be_empty() {
+    let error = SafetyPolicy::new().steps(3, 1).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("Step sizes 3..=1 allow no steps"),
+        "{}",
+        error
+    );
+    assert!(SafetyPolicy::new().steps(2, 2).is_ok());
+}
@@ -0,0 +1,105 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use aoc::scaffold;
+
+/// A copy of the files `new_day` reads, in a fresh directory.
+fn copy_layout(name: &str) -> PathBuf {
+    let source = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    for file in [
+        "Cargo.toml",
+        "answers.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/days.rs",
+        "template/Cargo.toml",
+        "template/src/lib.rs",
+    ] {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::copy(source.join(file), path).unwrap();
+    }
+
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+/// The lines of `contents` that start with `prefix`.
+fn lines_starting<'a>(contents: &'a str, prefix: &str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| line.starts_with(prefix))
+        .collect()
+}
+
+#[test]
+fn new_day_registers_the_crate_in_order() {
+    let root = copy_layout("registers");
+    scaffold::new_day(&root, 12).unwrap();
+
+    let workspace = read(&root, "Cargo.toml");
+    let members = lines_starting(&workspace, "    \"");
+    assert_eq!(
+        &members[members.len() - 3..],
+        ["    \"day11\",", "    \"day12\",", "    \"template\","]
+    );
+
+    let runner = read(&root, "aoc/Cargo.toml");
+    let days = lines_starting(&runner, "day");
+    assert_eq!(days.last(), Some(&"day12 = { path = \"../day12\" }"));
+
+    let registry = read(&root, "aoc/src/days.rs");
+    assert_eq!(
+        lines_starting(&registry, "use day").last(),
+        Some(&"use day12::Day12;")
+    );
+    assert_eq!(
+        lines_starting(&registry, "    Day::new::<Day").last(),
+        Some(&"    Day::new::<Day12>(12),")
+    );
+
+    assert!(read(&root, "day12/Cargo.toml").contains("name = \"day12\""));
+    assert!(read(&root, "day12/src/lib.rs").contains("pub struct Day12;"));
+    assert!(read(&root, "answers.toml").contains("day = 12\ninput = \"examples/12.txt\""));
+    assert!(root.join("inputs/12.txt").exists());
+    assert!(root.join("examples/12.txt").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn new_day_refuses_to_run_twice() {
+    let root = copy_layout("twice");
+    scaffold::new_day(&root, 12).unwrap();
+    let registry = read(&root, "aoc/src/days.rs");
+
+    let error = scaffold::new_day(&root, 12).unwrap_err();
+    assert!(error.to_string().contains("already exists"), "{}", error);
+    assert_eq!(read(&root, "aoc/src/days.rs"), registry);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn new_day_refuses_registered_days() {
+    let root = copy_layout("registered");
+
+    let error = scaffold::new_day(&root, 1).unwrap_err();
+    assert!(
+        error.to_string().contains("already registered"),
+        "{}",
+        error
+    );
+    assert!(!root.join("day01").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
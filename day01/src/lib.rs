@@ -0,0 +1,298 @@
+use aoc_common::{
+    parse::{self, Span},
+    Answer, Diagnostic, Error, Rng, Solution, Table,
+};
+use std::{cell::OnceCell, cmp::Reverse, collections::HashMap, iter};
+use tracing::debug;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Model = Lists;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(get_lists(input)?)
+    }
+
+    fn part1(lists: &Self::Model) -> Result<Answer, Error> {
+        Ok(lists.distance().into())
+    }
+
+    fn part2(lists: &Self::Model) -> Result<Answer, Error> {
+        Ok(lists.similarity().into())
+    }
+
+    fn report(lists: &Self::Model) -> Result<Vec<Table>, Error> {
+        let mut tables = lists.matrices();
+        tables.push(lists.statistics());
+
+        Ok(tables)
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_lists(rng, size.unwrap_or(INPUT_PAIRS)))
+    }
+}
+
+/// The historians' lists of location IDs, one per column of the input. Every
+/// list is the same length, and IDs are never negative.
+pub struct Lists {
+    columns: Vec<Vec<u32>>,
+    // The report on the first two lists, only worked out if it is asked for
+    report: OnceCell<Report>,
+}
+
+impl Lists {
+    fn new(columns: Vec<Vec<u32>>) -> Self {
+        Lists {
+            columns,
+            report: OnceCell::new(),
+        }
+    }
+
+    /// Total distance between the first two lists when they are paired up
+    /// smallest first.
+    pub fn distance(&self) -> u64 {
+        distance(&self.columns[0], &self.columns[1])
+    }
+
+    /// Each ID in the first list times how often it appears in the second.
+    pub fn similarity(&self) -> u64 {
+        Frequencies::new(&self.columns[0], &self.columns[1]).similarity()
+    }
+
+    /// The report on the first two lists, which are the ones the puzzle asks
+    /// about.
+    pub fn report(&self) -> &Report {
+        self.report
+            .get_or_init(|| Report::new(&self.columns[0], &self.columns[1]))
+    }
+
+    /// Everything there is to know about lists `first` and `second` (counted
+    /// from 0).
+    pub fn compare(&self, first: usize, second: usize) -> Report {
+        Report::new(&self.columns[first], &self.columns[second])
+    }
+
+    /// The distance and the similarity score between every pair of lists,
+    /// each as a matrix with a row and a column per list.
+    pub fn matrices(&self) -> Vec<Table> {
+        let names: Vec<String> = (1..=self.columns.len()).map(|n| n.to_string()).collect();
+        let mut distance =
+            Table::new(iter::once("distance").chain(names.iter().map(String::as_str)));
+        let mut similarity =
+            Table::new(iter::once("similarity").chain(names.iter().map(String::as_str)));
+
+        for (first, name) in names.iter().enumerate() {
+            let reports: Vec<Report> = (0..self.columns.len())
+                .map(|second| self.compare(first, second))
+                .collect();
+            distance.push_row(
+                iter::once(name.clone()).chain(reports.iter().map(|r| r.distance.to_string())),
+            );
+            similarity.push_row(
+                iter::once(name.clone()).chain(reports.iter().map(|r| r.similarity.to_string())),
+            );
+        }
+
+        vec![distance, similarity]
+    }
+
+    /// The rest of the report on the first two lists, one statistic per row.
+    pub fn statistics(&self) -> Table {
+        let report = self.report();
+        let most_frequent: Vec<String> = report
+            .most_frequent
+            .iter()
+            .map(|(id, count)| format!("{} x{}", id, count))
+            .collect();
+
+        let mut table = Table::new(["statistic", "value"]);
+        table.push_row(["shared IDs".to_string(), report.shared.to_string()]);
+        table.push_row([
+            "IDs only in list 1".to_string(),
+            report.only_first.to_string(),
+        ]);
+        table.push_row([
+            "IDs only in list 2".to_string(),
+            report.only_second.to_string(),
+        ]);
+        table.push_row(["most frequent IDs".to_string(), most_frequent.join(", ")]);
+
+        table
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    /// Total distance between the IDs when both lists are paired up smallest
+    /// first.
+    pub distance: u64,
+    /// Each ID in the first list times how often it appears in the second.
+    pub similarity: u64,
+    /// Distinct IDs that appear in both lists.
+    pub shared: usize,
+    /// Distinct IDs that only appear in the first list.
+    pub only_first: usize,
+    /// Distinct IDs that only appear in the second list.
+    pub only_second: usize,
+    /// The IDs seen most often across both lists and how often, most first.
+    pub most_frequent: Vec<(u32, u64)>,
+}
+
+impl Report {
+    /// Compares two lists with one sort of each and one count of every ID.
+    pub fn new(first: &[u32], second: &[u32]) -> Self {
+        let frequencies = Frequencies::new(first, second);
+        let report = Report {
+            distance: distance(first, second),
+            similarity: frequencies.similarity(),
+            shared: frequencies.shared(),
+            only_first: frequencies.only_first(),
+            only_second: frequencies.only_second(),
+            most_frequent: frequencies.most_frequent(MOST_FREQUENT),
+        };
+        debug!(?report, "report");
+
+        report
+    }
+}
+
+/// How many times each ID appears in each list.
+pub struct Frequencies {
+    counts: HashMap<u32, [u64; 2]>,
+}
+
+impl Frequencies {
+    pub fn new(first: &[u32], second: &[u32]) -> Self {
+        let mut counts: HashMap<u32, [u64; 2]> = HashMap::new();
+        for id in first {
+            counts.entry(*id).or_default()[0] += 1;
+        }
+        for id in second {
+            counts.entry(*id).or_default()[1] += 1;
+        }
+
+        Frequencies { counts }
+    }
+
+    /// The similarity score, counting each distinct ID once rather than
+    /// rescanning the second list for every entry in the first.
+    pub fn similarity(&self) -> u64 {
+        self.counts
+            .iter()
+            .map(|(id, [first, second])| *id as u64 * first * second)
+            .sum()
+    }
+
+    pub fn shared(&self) -> usize {
+        self.count(|[first, second]| first > 0 && second > 0)
+    }
+
+    pub fn only_first(&self) -> usize {
+        self.count(|[_, second]| second == 0)
+    }
+
+    pub fn only_second(&self) -> usize {
+        self.count(|[first, _]| first == 0)
+    }
+
+    /// The `n` IDs seen most often across both lists, ties broken by the
+    /// smaller ID.
+    pub fn most_frequent(&self, n: usize) -> Vec<(u32, u64)> {
+        let mut totals: Vec<(u32, u64)> = self
+            .counts
+            .iter()
+            .map(|(id, [first, second])| (*id, first + second))
+            .collect();
+        totals.sort_unstable_by_key(|(id, total)| (Reverse(*total), *id));
+        totals.truncate(n);
+
+        totals
+    }
+
+    fn count<P>(&self, predicate: P) -> usize
+    where
+        P: Fn([u64; 2]) -> bool,
+    {
+        self.counts
+            .values()
+            .filter(|counts| predicate(**counts))
+            .count()
+    }
+}
+
+/// Sorts both lists and adds up the distance between each pair.
+fn distance(first: &[u32], second: &[u32]) -> u64 {
+    let mut first_sorted = first.to_vec();
+    let mut second_sorted = second.to_vec();
+    first_sorted.sort_unstable();
+    second_sorted.sort_unstable();
+    debug!(?first_sorted, "first list sorted");
+    debug!(?second_sorted, "second list sorted");
+
+    first_sorted
+        .iter()
+        .zip(&second_sorted)
+        .map(|(first, second)| first.abs_diff(*second) as u64)
+        .sum()
+}
+
+fn get_lists(list: &str) -> Result<Lists, Diagnostic> {
+    let mut columns: Vec<Vec<u32>> = Vec::new();
+
+    for line in parse::lines(list) {
+        let ids: Vec<Span> = line.split_whitespace().collect();
+        // The first row decides how many lists there are
+        if columns.is_empty() {
+            if ids.len() < 2 {
+                return Err(line.error("expected at least two location IDs"));
+            }
+            columns = vec![Vec::new(); ids.len()];
+        }
+
+        if let Some(extra) = ids.get(columns.len()) {
+            return Err(extra.error(format!("expected only {} location IDs", columns.len())));
+        }
+        if ids.len() < columns.len() {
+            return Err(line.error(format!(
+                "expected {} location IDs, found {}",
+                columns.len(),
+                ids.len()
+            )));
+        }
+
+        for (column, id) in columns.iter_mut().zip(ids) {
+            column.push(id.parse()?);
+        }
+    }
+
+    // Nothing at all is still two lists, just empty ones
+    if columns.is_empty() {
+        columns = vec![Vec::new(); 2];
+    }
+
+    Ok(Lists::new(columns))
+}
+
+/// `pairs` rows of location IDs. IDs are drawn from a pool about the size of
+/// the lists so some appear on both sides and some more than once.
+fn generate_lists(rng: &mut Rng, pairs: usize) -> String {
+    let pool: Vec<u64> = (0..pairs.max(1))
+        .map(|_| rng.range(MIN_ID..=MAX_ID))
+        .collect();
+
+    let mut input = String::new();
+    for _ in 0..pairs {
+        let first = rng.choose(&pool);
+        let second = rng.choose(&pool);
+        input += &format!("{}   {}\n", first, second);
+    }
+
+    input
+}
+
+const MOST_FREQUENT: usize = 5;
+const INPUT_PAIRS: usize = 1000;
+const MIN_ID: u64 = 10000;
+const MAX_ID: u64 = 99999;
@@ -0,0 +1,376 @@
+use aoc_common::{parse, Answer, Diagnostic, Error, Rng, Solution, Table};
+use serde::Serialize;
+use std::fmt;
+use tracing::debug;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Model = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(get_levels(input)?)
+    }
+
+    fn part1(reports: &Self::Model) -> Result<Answer, Error> {
+        Ok(count_safe(reports, &SafetyPolicy::new()).into())
+    }
+
+    fn part2(reports: &Self::Model) -> Result<Answer, Error> {
+        Ok(count_safe(reports, &DAMPENED).into())
+    }
+
+    fn report(reports: &Self::Model) -> Result<Vec<Table>, Error> {
+        let mut table = Table::new(["report", "assessment"]);
+        for (i, levels) in reports.iter().enumerate() {
+            let assessment = DAMPENED.assess(levels);
+            table.push_record([(i + 1).to_string(), assessment.to_string()], &assessment);
+        }
+
+        Ok(vec![table])
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_reports(rng, size.unwrap_or(INPUT_REPORTS)))
+    }
+}
+
+fn count_safe(reports: &[Vec<i32>], policy: &SafetyPolicy) -> usize {
+    let mut safe_reports = 0;
+
+    for report in reports {
+        match policy.removals(report) {
+            Some(removed) => {
+                debug!(?report, ?removed, "safe report");
+                safe_reports += 1;
+            }
+            None => debug!(?report, "unsafe report"),
+        }
+    }
+
+    safe_reports
+}
+
+fn get_levels(list: &str) -> Result<Vec<Vec<i32>>, Diagnostic> {
+    parse::records(parse::lines(list), |line| {
+        let levels = line.numbers()?;
+        if levels.is_empty() {
+            return Err(line.error("expected at least one level"));
+        }
+
+        Ok(levels)
+    })
+}
+
+/// What makes a report safe: how big each step between levels may be, which
+/// way they must go and how many levels the Problem Dampener may remove.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    min_step: u32,
+    max_step: u32,
+    direction: Direction,
+    max_removals: usize,
+}
+
+impl SafetyPolicy {
+    /// The reactor's own rules: steps of 1 to 3, all in the same direction,
+    /// with the dampener switched off.
+    pub const fn new() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Any,
+            max_removals: 0,
+        }
+    }
+
+    /// Allows steps from `min` to `max` in size, inclusive. Fails if `min`
+    /// is above `max`, as no step could be safe.
+    pub fn steps(self, min: u32, max: u32) -> Result<Self, Error> {
+        if min > max {
+            return Err(Error::new(format!(
+                "Step sizes {}..={} allow no steps, the smallest must not be above the largest",
+                min, max
+            )));
+        }
+
+        Ok(SafetyPolicy {
+            min_step: min,
+            max_step: max,
+            ..self
+        })
+    }
+
+    pub const fn direction(self, direction: Direction) -> Self {
+        SafetyPolicy { direction, ..self }
+    }
+
+    /// Lets the dampener remove up to `k` levels.
+    pub const fn max_removals(self, k: usize) -> Self {
+        SafetyPolicy {
+            max_removals: k,
+            ..self
+        }
+    }
+
+    /// Why the report is or isn't safe: the first rule it breaks as it
+    /// stands, and what the dampener removed to make it safe.
+    pub fn assess(&self, levels: &[i32]) -> Assessment {
+        Assessment {
+            levels: levels.to_vec(),
+            violation: self.first_violation(levels),
+            removed: self.removals(levels),
+        }
+    }
+
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        self.removals(levels).is_some()
+    }
+
+    /// The indices of the fewest levels the dampener has to remove to make
+    /// the report safe, or `None` if that takes more than it is allowed.
+    pub fn removals(&self, levels: &[i32]) -> Option<Vec<usize>> {
+        let directions: &[Direction] = match self.direction {
+            Direction::Any => &[Direction::Increasing, Direction::Decreasing],
+            direction => &[direction],
+        };
+
+        directions
+            .iter()
+            .filter_map(|direction| self.removals_going(levels, *direction))
+            .min_by_key(|removed| removed.len())
+    }
+
+    /// Finds the longest run of kept levels going in `direction` with at most
+    /// `max_removals` levels skipped. Each level only looks back past as many
+    /// levels as could be removed, so this is O(n·k) rather than trying every
+    /// subset of removals.
+    fn removals_going(&self, levels: &[i32], direction: Direction) -> Option<Vec<usize>> {
+        let k = self.max_removals;
+        // For each level, the fewest removals before it if it is kept, and
+        // the kept level before it
+        let mut best: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(levels.len());
+        for (i, level) in levels.iter().enumerate() {
+            // Removing everything before this level
+            let mut current = (i <= k).then_some((i, None));
+            for j in (i.saturating_sub(k + 1)..i).rev() {
+                let Some((removed, _)) = best[j] else {
+                    continue;
+                };
+                let removed = removed + i - j - 1;
+                let better = current.is_none_or(|(fewest, _)| removed < fewest);
+                if better && removed <= k && self.allows(levels[j], *level, direction) {
+                    current = Some((removed, Some(j)));
+                }
+            }
+            best.push(current);
+        }
+
+        // The fewest removals overall, also removing everything after the
+        // last kept level
+        let (mut last, _) = best
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, best)| Some((i, best.as_ref()?.0 + levels.len() - 1 - i)))
+            .filter(|(_, removed)| *removed <= k)
+            .min_by_key(|(_, removed)| *removed)?;
+
+        let mut kept = vec![false; levels.len()];
+        loop {
+            kept[last] = true;
+            match best[last] {
+                Some((_, Some(previous))) => last = previous,
+                _ => break,
+            }
+        }
+
+        Some((0..levels.len()).filter(|i| !kept[*i]).collect())
+    }
+
+    fn first_violation(&self, levels: &[i32]) -> Option<Violation> {
+        // With any direction allowed, the first step that goes anywhere
+        // decides it
+        let mut direction = self.direction;
+        for (i, pair) in levels.windows(2).enumerate() {
+            // Widened so steps between extreme levels can't overflow
+            let delta = i64::from(pair[1]) - i64::from(pair[0]);
+            let going = match delta.signum() {
+                1 => Some(Direction::Increasing),
+                -1 => Some(Direction::Decreasing),
+                _ => None,
+            };
+
+            let kind = match (direction, going) {
+                (Direction::Any, Some(going)) => {
+                    direction = going;
+                    None
+                }
+                (required, Some(going)) if going != required => Some(ViolationKind::Direction),
+                _ => None,
+            };
+            let kind = kind.or_else(|| {
+                let size = delta.unsigned_abs();
+                (size < u64::from(self.min_step) || size > u64::from(self.max_step))
+                    .then_some(ViolationKind::StepSize)
+            });
+
+            if let Some(kind) = kind {
+                return Some(Violation {
+                    index: i + 1,
+                    kind,
+                    delta,
+                });
+            }
+        }
+
+        None
+    }
+
+    fn allows(&self, from: i32, to: i32, direction: Direction) -> bool {
+        let (from, to) = (i64::from(from), i64::from(to));
+        let step = match direction {
+            Direction::Decreasing => from - to,
+            _ => to - from,
+        };
+
+        (i64::from(self.min_step)..=i64::from(self.max_step)).contains(&step)
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy::new()
+    }
+}
+
+/// A report checked against a `SafetyPolicy`. Displays as the levels with the
+/// first violation in brackets and removed levels in parentheses, followed
+/// by the verdict.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Assessment {
+    pub levels: Vec<i32>,
+    /// The first rule the report breaks before anything is removed.
+    pub violation: Option<Violation>,
+    /// The indices of the levels the dampener removed, which is empty if the
+    /// report was safe as it was, or `None` if it couldn't be made safe.
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Assessment {
+    pub fn is_safe(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+impl fmt::Display for Assessment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let removed = self.removed.as_deref().unwrap_or_default();
+        let levels: Vec<String> = self
+            .levels
+            .iter()
+            .enumerate()
+            .map(|(i, level)| {
+                let mut level = level.to_string();
+                if removed.contains(&i) {
+                    level = format!("({})", level);
+                }
+                if self.violation.is_some_and(|violation| violation.index == i) {
+                    level = format!("[{}]", level);
+                }
+                level
+            })
+            .collect();
+        write!(f, "{}: ", levels.join(" "))?;
+
+        match (&self.removed, &self.violation) {
+            (Some(_), None) => write!(f, "safe"),
+            (Some(removed), Some(violation)) => {
+                let noun = if removed.len() == 1 {
+                    "index"
+                } else {
+                    "indices"
+                };
+                let removed: Vec<String> = removed.iter().map(|i| i.to_string()).collect();
+                write!(
+                    f,
+                    "safe after removing {} {}; {}",
+                    noun,
+                    removed.join(", "),
+                    violation
+                )
+            }
+            (None, Some(violation)) => write!(f, "unsafe; {}", violation),
+            (None, None) => write!(f, "unsafe"),
+        }
+    }
+}
+
+/// The step into the level at `index` broke a rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    pub index: usize,
+    pub kind: ViolationKind,
+    /// The change from the previous level.
+    pub delta: i64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rule = match self.kind {
+            ViolationKind::StepSize => "outside the allowed step size",
+            ViolationKind::Direction => "against the direction",
+        };
+
+        write!(
+            f,
+            "index {} steps by {:+}, {}",
+            self.index, self.delta, rule
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationKind {
+    StepSize,
+    Direction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Any,
+    Increasing,
+    Decreasing,
+}
+
+/// `reports` lines of levels. Each starts out safe and then has up to two
+/// levels disturbed, so there is a mix of safe reports, reports the dampener
+/// can fix and unsafe ones.
+fn generate_reports(rng: &mut Rng, reports: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..reports {
+        let length = rng.range(5..=8) as usize;
+        let increasing = rng.chance(0.5);
+        let mut levels = vec![rng.range(30..=70) as i64];
+        for _ in 1..length {
+            let step = rng.range(1..=3) as i64;
+            let last = levels[levels.len() - 1];
+            levels.push(if increasing { last + step } else { last - step });
+        }
+
+        for _ in 0..rng.below(3) {
+            let index = rng.below(length);
+            levels[index] += rng.range(0..=8) as i64 - 4;
+        }
+
+        let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+        input += &levels.join(" ");
+        input.push('\n');
+    }
+
+    input
+}
+
+const DAMPENED: SafetyPolicy = SafetyPolicy::new().max_removals(1);
+const INPUT_REPORTS: usize = 1000;
@@ -0,0 +1,133 @@
+use aoc_common::{Answer, Error, Rng, Solution};
+use regex::Regex;
+use tracing::debug;
+
+const REGEX: &str = r"mul\(\d{1,3},\d{1,3}\)";
+const DO_TEXT: &str = r"do()";
+const DONT_TEXT: &str = r"don't()";
+const INPUT_INSTRUCTIONS: usize = 900;
+const INPUT_LINES: usize = 6;
+const JUNK: &[char] = &[
+    '!', '@', '#', '$', '%', '^', '&', '*', '[', ']', '{', '}', '<', '>', '?', '/', '\'', ' ', ';',
+    ':', '+', '-', '~', ',', '(', ')',
+];
+const DECOYS: &[&str] = &[
+    "what", "how", "who", "where", "when", "why", "from", "select",
+];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = String;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Model) -> Result<Answer, Error> {
+        Ok(get_total(input).into())
+    }
+
+    fn part2(input: &Self::Model) -> Result<Answer, Error> {
+        let mut dos = input.match_indices(DO_TEXT);
+        let mut donts = input.match_indices(DONT_TEXT);
+        let mut total: u64 = 0;
+        let mut current_index: usize = 0;
+        let mut accept_input = true;
+        let mut current_do = dos.next();
+        let mut current_dont = donts.next();
+
+        while current_index < input.len() {
+            let next_index: usize;
+            let mut next_accept_input = accept_input;
+            if let Some(do_index) = &current_do {
+                if let Some(dont_index) = &current_dont {
+                    if do_index.0 < dont_index.0 {
+                        next_index = do_index.0 + DO_TEXT.len();
+                        next_accept_input = true;
+                        current_do = dos.next();
+                    } else {
+                        next_index = dont_index.0 + DONT_TEXT.len();
+                        next_accept_input = false;
+                        current_dont = donts.next();
+                    }
+                } else {
+                    next_index = do_index.0 + DO_TEXT.len();
+                    next_accept_input = true;
+                    current_do = dos.next();
+                }
+            } else if let Some(dont_index) = &current_dont {
+                next_index = dont_index.0 + DONT_TEXT.len();
+                next_accept_input = false;
+                current_dont = donts.next();
+            } else {
+                // we don't have any more dos/donts
+                next_index = input.len();
+            }
+
+            if accept_input {
+                total += get_total(&input[current_index..next_index]);
+            }
+
+            accept_input = next_accept_input;
+            current_index = next_index;
+        }
+
+        Ok(total.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_memory(rng, size.unwrap_or(INPUT_INSTRUCTIONS)))
+    }
+}
+
+fn get_total(input: &str) -> u64 {
+    let regex = Regex::new(REGEX).unwrap();
+    let mut total: u64 = 0;
+    for mul in regex.find_iter(input).map(|c| c.as_str()) {
+        let numbers = &mut mul[4..mul.len() - 1].rsplit(",");
+        let second = numbers.next().unwrap().parse::<u64>().unwrap();
+        let first = numbers.next().unwrap().parse::<u64>().unwrap();
+        let result = first * second;
+        debug!(mul, result);
+        total += result;
+    }
+
+    total
+}
+
+/// Corrupted memory holding `instructions` instructions, mostly real `mul`s
+/// with some `do()`s, `don't()`s, broken `mul`s and other calls mixed in, all
+/// separated by junk and spread over a few lines.
+fn generate_memory(rng: &mut Rng, instructions: usize) -> String {
+    let per_line = instructions.div_ceil(INPUT_LINES).max(1);
+
+    let mut memory = String::new();
+    for i in 0..instructions {
+        if i > 0 && i % per_line == 0 {
+            memory.push('\n');
+        }
+
+        for _ in 0..rng.below(4) {
+            memory.push(*rng.choose(JUNK));
+        }
+
+        let first = rng.range(1..=999);
+        let second = rng.range(1..=999);
+        let instruction = match rng.below(20) {
+            0 => DO_TEXT.to_string(),
+            1 => DONT_TEXT.to_string(),
+            2 => format!("mul({},{}{}", first, second, rng.choose(JUNK)),
+            3 => format!("mul ( {} , {} )", first, second),
+            4 => format!("mul({},{})", rng.range(1000..=9999), second),
+            5 => format!("mul[{},{}]", first, second),
+            6 => format!("{}({},{})", rng.choose(DECOYS), first, second),
+            7 => format!("{}()", rng.choose(DECOYS)),
+            _ => format!("mul({},{})", first, second),
+        };
+        memory += &instruction;
+    }
+    memory.push('\n');
+
+    memory
+}
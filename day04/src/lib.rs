@@ -0,0 +1,201 @@
+use aoc_common::{Answer, Cell, Color, Direction, Error, Grid, Rng, Solution, Style};
+use tracing::{debug, trace};
+
+const TARGET_TEXT: &str = r"XMAS";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Model = Crossword;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Crossword::parse_input(input)
+    }
+
+    fn part1(crossword: &Self::Model) -> Result<Answer, Error> {
+        Ok(crossword.get_xmases().into())
+    }
+
+    fn part2(crossword: &Self::Model) -> Result<Answer, Error> {
+        Ok(crossword.get_crossed_xmases().into())
+    }
+
+    fn render(crossword: &Self::Model) -> Result<Option<Grid<Cell>>, Error> {
+        Ok(Some(crossword.render()))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_crossword(rng, size.unwrap_or(INPUT_SIZE)))
+    }
+}
+
+pub struct Crossword {
+    board: Grid<char>,
+}
+
+impl Crossword {
+    fn parse_input(input: &str) -> Result<Self, Error> {
+        Ok(Crossword {
+            board: Grid::parse(input)?,
+        })
+    }
+
+    fn get_xmases(&self) -> u64 {
+        let mut total: u64 = 0;
+        for position in self.board.find_all(|letter| *letter == 'X') {
+            total += self.check_x(position);
+        }
+
+        total
+    }
+
+    fn check_x(&self, position: (usize, usize)) -> u64 {
+        debug!(?position, "X found");
+        let mut total = 0;
+        for direction in Direction::ALL {
+            if self.check_xmas(position, direction) {
+                debug!(?direction, "XMAS found");
+                total += 1;
+            }
+        }
+
+        total
+    }
+
+    fn check_xmas(&self, position: (usize, usize), direction: Direction) -> bool {
+        let mut position = Some(position);
+        for target_letter in TARGET_TEXT.chars() {
+            let Some(current) = position else {
+                trace!(?direction, "ran off the board");
+                return false;
+            };
+
+            let letter = self.board[current];
+            if letter != target_letter {
+                trace!(?current, %letter, %target_letter, ?direction, "letter mismatch");
+                return false;
+            }
+
+            position = self.board.offset(current, direction.vector());
+        }
+
+        true
+    }
+
+    /// The board with every letter that is part of an XMAS highlighted.
+    fn render(&self) -> Grid<Cell> {
+        let mut matched = self.board.map(|_| false);
+        for position in self.board.find_all(|letter| *letter == 'X') {
+            for direction in Direction::ALL {
+                if !self.check_xmas(position, direction) {
+                    continue;
+                }
+
+                let mut current = Some(position);
+                for _ in TARGET_TEXT.chars() {
+                    let Some(letter) = current else {
+                        break;
+                    };
+                    matched[letter] = true;
+                    current = self.board.offset(letter, direction.vector());
+                }
+            }
+        }
+
+        let mut cells = self.board.map(|letter| Cell::new(*letter, UNMATCHED_STYLE));
+        for position in matched.find_all(|matched| *matched) {
+            cells[position].style = MATCHED_STYLE;
+        }
+
+        cells
+    }
+
+    fn get_crossed_xmases(&self) -> u64 {
+        let mut total: u64 = 0;
+        for position in self.board.find_all(|letter| *letter == 'A') {
+            debug!(?position, "A found");
+            total += self.check_a(position);
+        }
+
+        total
+    }
+
+    fn check_a(&self, position: (usize, usize)) -> u64 {
+        let mut total: u64 = 0;
+        // North is empty between M's
+        if self.check_point(position, Direction::NorthWest, 'M')
+            && self.check_point(position, Direction::NorthEast, 'M')
+            && self.check_point(position, Direction::SouthEast, 'S')
+            && self.check_point(position, Direction::SouthWest, 'S')
+        {
+            debug!("Found North centered cross");
+            total += 1;
+        }
+
+        // East is empty between M's
+        if self.check_point(position, Direction::NorthEast, 'M')
+            && self.check_point(position, Direction::SouthEast, 'M')
+            && self.check_point(position, Direction::NorthWest, 'S')
+            && self.check_point(position, Direction::SouthWest, 'S')
+        {
+            debug!("Found East centered cross");
+            total += 1;
+        }
+
+        // South is empty between M's
+        if self.check_point(position, Direction::SouthEast, 'M')
+            && self.check_point(position, Direction::SouthWest, 'M')
+            && self.check_point(position, Direction::NorthWest, 'S')
+            && self.check_point(position, Direction::NorthEast, 'S')
+        {
+            debug!("Found South centered cross");
+            total += 1;
+        }
+
+        // West is empty between M's
+        if self.check_point(position, Direction::SouthWest, 'M')
+            && self.check_point(position, Direction::NorthWest, 'M')
+            && self.check_point(position, Direction::NorthEast, 'S')
+            && self.check_point(position, Direction::SouthEast, 'S')
+        {
+            debug!("Found West centered cross");
+            total += 1;
+        }
+
+        total
+    }
+
+    fn check_point(&self, position: (usize, usize), direction: Direction, letter: char) -> bool {
+        match self.board.offset(position, direction.vector()) {
+            Some(neighbour) => self.board[neighbour] == letter,
+            None => false,
+        }
+    }
+}
+
+const MATCHED_STYLE: Style = Style::new().foreground(Color::BrightGreen).bold();
+const UNMATCHED_STYLE: Style = Style::new().dim();
+
+/// A `size` by `size` square of random letters from XMAS, with extra copies
+/// of the word written in every direction so there is plenty to find.
+fn generate_crossword(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let letters: Vec<char> = TARGET_TEXT.chars().collect();
+    let mut board = Grid::new(size, size, ()).map(|_| *rng.choose(&letters));
+
+    for _ in 0..size * size / 10 {
+        let direction = *rng.choose(&Direction::ALL);
+        let mut position = Some((rng.below(size), rng.below(size)));
+        for letter in &letters {
+            let Some(current) = position else {
+                break;
+            };
+            board[current] = *letter;
+            position = board.offset(current, direction.vector());
+        }
+    }
+
+    board.to_string()
+}
+
+const INPUT_SIZE: usize = 140;
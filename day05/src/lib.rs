@@ -0,0 +1,197 @@
+use aoc_common::{
+    graph::{self, Graph},
+    parse::{self, Span},
+    Answer, Diagnostic, Error, Rng, Solution,
+};
+use std::collections::HashSet;
+use tracing::{debug, trace};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = (Rules, Vec<Change>);
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((rules, changes): &Self::Model) -> Result<Answer, Error> {
+        let mut total: u64 = 0;
+
+        for change in changes {
+            if rules.satisfies_rules(change) {
+                debug!(
+                    ?change,
+                    middle_page = change.get_middle_page(),
+                    "satisfies rules"
+                );
+                total += change.get_middle_page();
+            } else {
+                debug!(?change, "does not satisfy rules");
+            }
+        }
+
+        Ok(total.into())
+    }
+
+    fn part2((rules, changes): &Self::Model) -> Result<Answer, Error> {
+        let mut total: u64 = 0;
+
+        for change in changes {
+            if rules.satisfies_rules(change) {
+                debug!(?change, "satisfies rules");
+            } else {
+                debug!(?change, "does not satisfy rules, fixing");
+                let mut change = change.clone();
+                change.fix(rules)?;
+                let middle_page = change.get_middle_page();
+                debug!(?change, middle_page, "fixed order");
+                total += middle_page;
+            }
+        }
+
+        Ok(total.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_manual(rng, size.unwrap_or(INPUT_UPDATES)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Change>), Diagnostic> {
+    let sections = parse::sections(input);
+    let (rule_lines, change_lines) = match sections.as_slice() {
+        [] => (&[][..], &[][..]),
+        [rule_lines] => (&rule_lines[..], &[][..]),
+        [rule_lines, change_lines] => (&rule_lines[..], &change_lines[..]),
+        [_, _, extra, ..] => {
+            return Err(extra[0].error("expected only rules and updates"));
+        }
+    };
+
+    let mut rules = Rules::new();
+    for line in rule_lines {
+        rules.parse_rule(*line)?;
+    }
+    let changes = parse::records(change_lines.iter().copied(), Change::parse)?;
+
+    Ok((rules, changes))
+}
+
+/// Page ordering rules as a graph with an edge from each page to every page
+/// that must come after it.
+#[derive(Debug)]
+pub struct Rules {
+    rules: Graph<u64>,
+}
+
+impl Rules {
+    fn new() -> Self {
+        Rules {
+            rules: Graph::new(),
+        }
+    }
+
+    fn parse_rule(&mut self, rule: Span) -> Result<(), Diagnostic> {
+        trace!(rule = rule.text(), "parsing rule");
+        let (first, second) = rule.pair(RULE_TOKEN)?;
+        self.rules.add_edge(first, second);
+
+        Ok(())
+    }
+
+    fn satisfies_rules(&self, change: &Change) -> bool {
+        let mut previous = Vec::new();
+        for page in &change.changes {
+            for previous_page in &previous {
+                if self.rules.contains_edge(page, previous_page) {
+                    return false;
+                }
+            }
+            previous.push(*page);
+        }
+
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Change {
+    pub changes: Vec<u64>,
+}
+
+impl Change {
+    fn parse(input: Span) -> Result<Self, Diagnostic> {
+        trace!(input = input.text(), "parsing change");
+
+        Ok(Change {
+            changes: input.list(PAGE_TOKEN)?,
+        })
+    }
+
+    fn get_middle_page(&self) -> &u64 {
+        self.changes.get(self.changes.len() / 2).unwrap()
+    }
+
+    /// Reorders the pages so every rule between them is satisfied.
+    fn fix(&mut self, rules: &Rules) -> Result<(), Error> {
+        // Only the rules between pages in this change apply
+        let pages: HashSet<u64> = self.changes.iter().copied().collect();
+        let pages = &pages;
+        let order = graph::topological_sort(self.changes.clone(), |page| {
+            rules
+                .rules
+                .successors(page)
+                .iter()
+                .copied()
+                .filter(move |next| pages.contains(next))
+        })
+        .map_err(|cycle| Error::new(format!("Rules for pages {:?} form a cycle", cycle.nodes)))?;
+        self.changes = order;
+
+        Ok(())
+    }
+}
+
+/// Rules ordering a random set of pages, followed by `updates` updates. The
+/// rules come from one fixed order with a rule between every pair of pages,
+/// so they never form a cycle and every update has exactly one correct order.
+/// About half the updates are already in that order.
+fn generate_manual(rng: &mut Rng, updates: usize) -> String {
+    let mut pages: Vec<u64> = (MIN_PAGE..=MAX_PAGE).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(INPUT_PAGES);
+
+    let mut rules = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{}{}{}", before, RULE_TOKEN, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = rules.join("\n");
+    input += "\n\n";
+    for _ in 0..updates {
+        // An odd length so there is a middle page
+        let length = 2 * rng.range(2..=11) as usize + 1;
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut update);
+        update.truncate(length);
+        if rng.chance(0.5) {
+            update.sort_unstable();
+        }
+
+        let update: Vec<String> = update.iter().map(|&i| pages[i].to_string()).collect();
+        input += &update.join(&PAGE_TOKEN.to_string());
+        input.push('\n');
+    }
+
+    input
+}
+const RULE_TOKEN: char = '|';
+const PAGE_TOKEN: char = ',';
+const INPUT_PAGES: usize = 49;
+const INPUT_UPDATES: usize = 200;
+const MIN_PAGE: u64 = 10;
+const MAX_PAGE: u64 = 99;
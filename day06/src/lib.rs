@@ -0,0 +1,339 @@
+use aoc_common::{
+    parse, Answer, Cell, Color, Diagnostic, Direction, Error, Frames, Grid, Point, Rng, Solution,
+    Style,
+};
+use std::fmt;
+use tracing::{debug, trace};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Map;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Answer, Error> {
+        let mut map = map.clone();
+        map.run()?;
+
+        Ok(map.spaces_visited.into())
+    }
+
+    fn part2(map: &Self::Model) -> Result<Answer, Error> {
+        let mut loops: u64 = 0;
+        // The guard would notice an object dropped on top of them
+        let start = map.guard.position.to_index();
+        for (x, y) in map
+            .board
+            .find_all(|space| space.space_type == SpaceType::Empty)
+            .filter(|position| Some(*position) != start)
+        {
+            debug!(x, y, "trying object in empty space");
+            let mut map_copy = map.clone();
+            map_copy.board[(x, y)].space_type = SpaceType::Object;
+            if map_copy.has_loop() {
+                loops += 1;
+            }
+        }
+
+        Ok(loops.into())
+    }
+
+    fn render(map: &Self::Model) -> Result<Option<Grid<Cell>>, Error> {
+        Ok(Some(map.render()?))
+    }
+
+    fn animate(map: &Self::Model, frames: &mut Frames) -> Result<(), Error> {
+        let mut map = map.clone();
+        frames.push_with(|| map.frame());
+        map.walk(|map| frames.push_with(|| map.frame()))?;
+        frames.finish_with(|| map.frame());
+
+        Ok(())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_map(rng, size.unwrap_or(INPUT_SIZE)))
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    board: Grid<Space>,
+    guard: Guard,
+    spaces_visited: u64,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let board = Grid::parse_with(input, Space::parse)?;
+        let guards: Vec<(usize, usize)> = Grid::parse(input)?.find_all(|c| *c == GUARD).collect();
+        let Some(&(x, y)) = guards.first() else {
+            return Err(Error::new("Did not find guard in map!".to_string()));
+        };
+        if let Some(&(x, y)) = guards.get(1) {
+            // The grid has no blank lines, so rows and lines match up
+            let line = parse::lines(input)[y];
+            return Err(Diagnostic::at_char(
+                "found a second guard",
+                line.line_number(),
+                line.text(),
+                x,
+            )
+            .into());
+        }
+
+        Ok(Map {
+            board,
+            guard: Guard {
+                position: Point::from((x, y)),
+                direction: Direction::North,
+            },
+            // A guard is an empty space that has been visited
+            spaces_visited: 1,
+        })
+    }
+
+    fn run(&mut self) -> Result<(), Error> {
+        self.walk(|_| {})
+    }
+
+    /// Walks the guard off the board, calling `on_step` after every move or
+    /// turn. Fails if the guard ends up going round in circles instead, which
+    /// the puzzle promises doesn't happen.
+    fn walk<F>(&mut self, on_step: F) -> Result<(), Error>
+    where
+        F: FnMut(&Self),
+    {
+        if self.patrol(on_step) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "The guard never leaves the map, they are back at {} facing {}",
+                self.guard.position, self.guard.direction
+            )))
+        }
+    }
+
+    /// Walks the guard until they leave the board or come back to a space
+    /// facing a way they have faced there before, returning whether they
+    /// left.
+    fn patrol<F>(&mut self, mut on_step: F) -> bool
+    where
+        F: FnMut(&Self),
+    {
+        // The guard's starting space has already been visited
+        if let Some(start) = self.board.get_point_mut(self.guard.position) {
+            start.visit(self.guard.direction);
+        }
+
+        loop {
+            // Get the next move of the guard
+            let next = self.guard.get_next_move();
+            let Some(space) = self.board.get_point_mut(next) else {
+                trace!(guard = %self.guard, "next move leaves the board");
+                return true;
+            };
+            trace!(guard = %self.guard, %next, "next move");
+
+            let repeated = if space.can_visit() {
+                trace!(%next, "visitable, moving");
+                self.guard.move_to(next);
+
+                if !space.is_visited() {
+                    self.spaces_visited += 1;
+                }
+                space.visit(self.guard.direction)
+            } else {
+                trace!(%next, "not visitable, rotating");
+                self.guard.rotate();
+
+                // Turning to face a way they have faced here before is a
+                // loop too, even without moving
+                self.board
+                    .get_point_mut(self.guard.position)
+                    .is_some_and(|current| current.visit(self.guard.direction))
+            };
+            on_step(self);
+
+            if repeated {
+                debug!(guard = %self.guard, "already been here facing this way");
+                return false;
+            }
+        }
+    }
+
+    /// The board after the guard has walked off it, with their path drawn
+    /// in and the starting position marked.
+    fn render(&self) -> Result<Grid<Cell>, Error> {
+        let mut walked = self.clone();
+        walked.run()?;
+
+        let mut cells = walked.board.map(Space::render);
+        if let Some(start) = self.guard.position.to_index() {
+            cells[start] = Cell::new(GUARD, START_STYLE);
+        }
+
+        Ok(cells)
+    }
+
+    /// The board part way through a walk, with the guard drawn facing the
+    /// way they are heading.
+    fn frame(&self) -> Grid<Cell> {
+        let mut cells = self.board.map(Space::render);
+        if let Some(position) = self.guard.position.to_index() {
+            cells[position] = Cell::new(self.guard.symbol(), START_STYLE);
+        }
+
+        cells
+    }
+
+    fn has_loop(&mut self) -> bool {
+        !self.patrol(|_| {})
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Guard {
+    fn get_next_move(&self) -> Point {
+        self.position + self.direction.vector()
+    }
+
+    fn move_to(&mut self, position: Point) {
+        self.position = position;
+    }
+
+    fn rotate(&mut self) {
+        self.direction = self.direction.clockwise();
+    }
+
+    fn symbol(&self) -> char {
+        match self.direction {
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+            _ => GUARD,
+        }
+    }
+}
+
+impl fmt::Display for Guard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Guard at {} facing {}", self.position, self.direction)
+    }
+}
+
+#[derive(Clone)]
+struct Space {
+    space_type: SpaceType,
+    visited: Vec<Direction>,
+}
+
+impl Space {
+    fn parse(space: char) -> Option<Self> {
+        Some(Space {
+            space_type: SpaceType::parse(space)?,
+            visited: Vec::new(),
+        })
+    }
+
+    fn can_visit(&self) -> bool {
+        self.space_type != SpaceType::Object
+    }
+
+    fn is_visited(&self) -> bool {
+        !self.visited.is_empty()
+    }
+
+    fn render(&self) -> Cell {
+        if self.space_type == SpaceType::Object {
+            return Cell::new(OBJECT, OBJECT_STYLE);
+        }
+
+        let vertical = self
+            .visited
+            .iter()
+            .any(|direction| matches!(direction, Direction::North | Direction::South));
+        let horizontal = self
+            .visited
+            .iter()
+            .any(|direction| matches!(direction, Direction::East | Direction::West));
+        match (vertical, horizontal) {
+            (true, true) => Cell::new('+', PATH_STYLE),
+            (true, false) => Cell::new('|', PATH_STYLE),
+            (false, true) => Cell::new('-', PATH_STYLE),
+            (false, false) => Cell::new(OPEN, OPEN_STYLE),
+        }
+    }
+
+    fn visit(&mut self, direction: Direction) -> bool {
+        if self.visited.contains(&direction) {
+            true
+        } else {
+            self.visited.push(direction);
+            false
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum SpaceType {
+    Empty,
+    Object,
+}
+
+impl SpaceType {
+    fn parse(space: char) -> Option<Self> {
+        match space {
+            OBJECT => Some(SpaceType::Object),
+            OPEN => Some(SpaceType::Empty),
+            GUARD => Some(SpaceType::Empty),
+            _ => None,
+        }
+    }
+}
+
+/// A `size` by `size` lab with objects scattered over it and the guard
+/// facing north from a random open space. Labs where the guard would never
+/// leave are thrown away and drawn again, as the puzzle promises they leave.
+fn generate_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let mut map = Grid::new(size, size, ()).map(|_| {
+            if rng.chance(OBJECT_CHANCE) {
+                OBJECT
+            } else {
+                OPEN
+            }
+        });
+        map[(rng.below(size), rng.below(size))] = GUARD;
+
+        let map = map.to_string();
+        let leaves = Map::parse(&map).is_ok_and(|mut lab| lab.run().is_ok());
+        if leaves {
+            return map;
+        }
+        debug!("generated map traps the guard, drawing another");
+    }
+}
+
+const INPUT_SIZE: usize = 130;
+const OBJECT_CHANCE: f64 = 0.05;
+
+// Things to define on the map
+const OBJECT: char = '#';
+const GUARD: char = '^';
+const OPEN: char = '.';
+
+// How the rendered map looks
+const OBJECT_STYLE: Style = Style::new().foreground(Color::Red).bold();
+const PATH_STYLE: Style = Style::new().foreground(Color::Yellow);
+const OPEN_STYLE: Style = Style::new().dim();
+const START_STYLE: Style = Style::new().foreground(Color::BrightCyan).bold();
@@ -0,0 +1,191 @@
+use aoc_common::{
+    parse::{self, Span},
+    Answer, Diagnostic, Error, Rng, Solution,
+};
+use std::fmt;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(parse::records(parse::lines(input), Equation::parse)?)
+    }
+
+    fn part1(equations: &Self::Model) -> Result<Answer, Error> {
+        Ok(total(equations, &Operator::list()).into())
+    }
+
+    fn part2(equations: &Self::Model) -> Result<Answer, Error> {
+        Ok(total(equations, &Operator::list_with_concatenation()).into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_equations(rng, size.unwrap_or(INPUT_EQUATIONS)))
+    }
+}
+
+fn total(equations: &[Equation], operators: &[Operator]) -> u64 {
+    let mut total: u64 = 0;
+
+    for eq in equations {
+        if let Some(result) = eq.solve(operators) {
+            total += result;
+        }
+    }
+
+    total
+}
+
+pub struct Equation {
+    target_total: u64,
+    operands: Vec<u64>,
+}
+
+impl Equation {
+    fn parse(line: Span) -> Result<Self, Diagnostic> {
+        let (total, operands) = line.split_once(TOTAL_TOKEN)?;
+        let target_total = total.parse()?;
+        let operands: Vec<u64> = operands.numbers()?;
+        if operands.is_empty() {
+            return Err(line.error("expected at least one operand"));
+        }
+
+        Ok(Self {
+            target_total,
+            operands,
+        })
+    }
+
+    fn solve(&self, operators: &[Operator]) -> Option<u64> {
+        solve_eq(
+            *self.operands.first().unwrap(),
+            self.target_total,
+            &self.operands[1..],
+            operators,
+        )
+    }
+}
+
+fn solve_eq(
+    current_total: u64,
+    target_total: u64,
+    remaining_numbers: &[u64],
+    operators: &[Operator],
+) -> Option<u64> {
+    if remaining_numbers.is_empty() {
+        if current_total == target_total {
+            return Some(target_total);
+        } else {
+            return None;
+        }
+    }
+
+    for operator in operators {
+        let new_total = operator.apply(current_total, remaining_numbers[0]);
+        if new_total <= target_total {
+            let remaining: &[u64] = if remaining_numbers.len() == 1 {
+                &[]
+            } else {
+                &remaining_numbers[1..]
+            };
+
+            let result = solve_eq(new_total, target_total, remaining, operators);
+            if result.is_some() {
+                return result;
+            }
+        }
+    }
+
+    None
+}
+
+enum Operator {
+    Addition,
+    Multiplication,
+    Concatenation,
+}
+
+impl Operator {
+    fn apply(&self, first: u64, second: u64) -> u64 {
+        match self {
+            Operator::Addition => first + second,
+            Operator::Multiplication => first * second,
+            Operator::Concatenation => {
+                let concat = first.to_string() + &second.to_string();
+                concat.parse::<u64>().unwrap()
+            }
+        }
+    }
+
+    fn list() -> [Operator; 2] {
+        [Operator::Addition, Operator::Multiplication]
+    }
+
+    fn list_with_concatenation() -> [Operator; 3] {
+        [
+            Operator::Addition,
+            Operator::Multiplication,
+            Operator::Concatenation,
+        ]
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self {
+            Operator::Addition => "Addition",
+            Operator::Multiplication => "Multiplication",
+            Operator::Concatenation => "Concatenation",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+/// `equations` lines of equations. Each total is worked out by combining the
+/// operands with random operators, then about half are nudged off so that no
+/// combination is likely to reach them.
+fn generate_equations(rng: &mut Rng, equations: usize) -> String {
+    let operators = Operator::list_with_concatenation();
+
+    let mut input = String::new();
+    for _ in 0..equations {
+        let mut operands = vec![generate_operand(rng)];
+        let mut total = operands[0];
+        for _ in 1..rng.range(2..=12) {
+            let operand = generate_operand(rng);
+            let next = rng.choose(&operators).apply(total, operand);
+            // Keep totals well inside a u64 so solving them cannot overflow
+            if next > MAX_TOTAL {
+                break;
+            }
+
+            operands.push(operand);
+            total = next;
+        }
+
+        if rng.chance(0.5) {
+            total += rng.range(1..=total);
+        }
+
+        let operands: Vec<String> = operands.iter().map(|operand| operand.to_string()).collect();
+        input += &format!("{}{} {}\n", total, TOTAL_TOKEN, operands.join(" "));
+    }
+
+    input
+}
+
+/// Mostly single digits like the real puzzle, with the odd larger number.
+fn generate_operand(rng: &mut Rng) -> u64 {
+    if rng.chance(0.3) {
+        rng.range(10..=999)
+    } else {
+        rng.range(1..=9)
+    }
+}
+
+const TOTAL_TOKEN: char = ':';
+const INPUT_EQUATIONS: usize = 850;
+const MAX_TOTAL: u64 = 999_999_999_999_999;
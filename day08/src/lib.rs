@@ -0,0 +1,177 @@
+use aoc_common::{Answer, Cell, Color, Error, Grid, Point, Rng, Solution, Style};
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Model = Map;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Answer, Error> {
+        Ok(map.num_antinodes(false).into())
+    }
+
+    fn part2(map: &Self::Model) -> Result<Answer, Error> {
+        Ok(map.num_antinodes(true).into())
+    }
+
+    fn render(map: &Self::Model) -> Result<Option<Grid<Cell>>, Error> {
+        Ok(Some(map.render()))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_map(rng, size.unwrap_or(INPUT_SIZE)))
+    }
+}
+
+pub struct Map {
+    grid: Grid<char>,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, Error> {
+        // Antennas are letters and digits
+        let grid = Grid::parse_with(input, |c| {
+            (c == EMPTY_SPACE || c.is_ascii_alphanumeric()).then_some(c)
+        })?;
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+        for ((x, y), frequency) in grid.iter() {
+            if *frequency != EMPTY_SPACE {
+                let antenna = Point::from((x, y));
+
+                if let Some(vec) = antennas.get_mut(frequency) {
+                    vec.push(antenna);
+                } else {
+                    antennas.insert(*frequency, vec![antenna]);
+                }
+            }
+        }
+
+        Ok(Self { grid, antennas })
+    }
+
+    fn num_antinodes(&self, resonant_harmonics: bool) -> u64 {
+        let antinodes = self.antinodes(resonant_harmonics);
+
+        antinodes.find_all(Option::is_some).count() as u64
+    }
+
+    /// Every location with an antinode, marked with the frequency of the
+    /// first antenna pair found to produce it.
+    fn antinodes(&self, resonant_harmonics: bool) -> Grid<Option<char>> {
+        let mut locations = Grid::new(self.grid.width(), self.grid.height(), None);
+        for frequency in self.frequencies() {
+            let antennas = &self.antennas[&frequency];
+            for (i, current) in antennas.iter().enumerate() {
+                for other in &antennas[i + 1..] {
+                    debug!(%current, %other, "checking antenna pair");
+                    let pair_antinodes = if resonant_harmonics {
+                        self.calculate_antinodes(current, other)
+                    } else {
+                        let delta = *other - *current;
+                        vec![*other + delta, *current - delta]
+                    };
+
+                    for antinode in pair_antinodes {
+                        trace!(%antinode, "antinode");
+                        if let Some(existing) = locations.get_point_mut(antinode) {
+                            existing.get_or_insert(frequency);
+                        }
+                    }
+                }
+            }
+        }
+
+        locations
+    }
+
+    /// The frequencies in a fixed order, so the same one wins when antinodes
+    /// overlap and keeps its colour from run to run.
+    fn frequencies(&self) -> Vec<char> {
+        let mut frequencies: Vec<char> = self.antennas.keys().copied().collect();
+        frequencies.sort_unstable();
+
+        frequencies
+    }
+
+    /// The map with the resonant harmonic antinodes drawn in, coloured by the
+    /// frequency that produced them. Antennas are drawn over their antinodes.
+    fn render(&self) -> Grid<Cell> {
+        let frequencies = self.frequencies();
+        let color = |frequency: char| {
+            let index = frequencies.binary_search(&frequency).unwrap_or_default();
+            Color::DISTINCT[index % Color::DISTINCT.len()]
+        };
+
+        let mut cells = self.antinodes(true).map(|antinode| match antinode {
+            Some(frequency) => Cell::new(ANTINODE, Style::new().foreground(color(*frequency))),
+            None => Cell::new(EMPTY_SPACE, EMPTY_STYLE),
+        });
+        for (position, frequency) in self.grid.iter() {
+            if *frequency != EMPTY_SPACE {
+                cells[position] = Cell::new(
+                    *frequency,
+                    Style::new().foreground(color(*frequency)).bold(),
+                );
+            }
+        }
+
+        cells
+    }
+
+    fn calculate_antinodes(&self, current: &Point, other: &Point) -> Vec<Point> {
+        // Step by the smallest move along the line, so points between the
+        // antennas are found too when they are more than one step apart
+        let delta = (*other - *current).reduced();
+
+        // The walk from the first node passes through both nodes
+        let mut antinodes = Vec::new();
+        let mut next = *current;
+        while self.grid.contains(next) {
+            antinodes.push(next);
+            next += delta;
+        }
+
+        let mut next = *current - delta;
+        while self.grid.contains(next) {
+            antinodes.push(next);
+            next -= delta;
+        }
+
+        antinodes
+    }
+}
+
+/// A `size` by `size` map with a handful of antennas on each of up to 62
+/// frequencies. Antennas never share a location.
+fn generate_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, EMPTY_SPACE);
+    let mut empty: Vec<(usize, usize)> = map.positions().collect();
+    rng.shuffle(&mut empty);
+
+    let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+    let count = (size * size / CELLS_PER_FREQUENCY).clamp(1, frequencies.len());
+    for frequency in &frequencies[..count] {
+        for _ in 0..rng.range(2..=4) {
+            let Some(position) = empty.pop() else {
+                break;
+            };
+            map[position] = *frequency;
+        }
+    }
+
+    map.to_string()
+}
+
+const INPUT_SIZE: usize = 50;
+const CELLS_PER_FREQUENCY: usize = 60;
+
+const EMPTY_SPACE: char = '.';
+const ANTINODE: char = '#';
+const EMPTY_STYLE: Style = Style::new().dim();
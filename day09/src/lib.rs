@@ -0,0 +1,308 @@
+use aoc_common::{
+    parse, Answer, Cell, Color, Diagnostic, Error, Frames, Grid, Rng, Solution, Style,
+};
+use std::fmt;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Model = Disk;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(Disk::parse(input)?)
+    }
+
+    fn part1(disk: &Self::Model) -> Result<Answer, Error> {
+        let mut disk = disk.clone();
+        disk.compact()?;
+
+        Ok(disk.checksum().into())
+    }
+
+    fn part2(disk: &Self::Model) -> Result<Answer, Error> {
+        let mut disk = disk.clone();
+        disk.defrag()?;
+
+        Ok(disk.checksum().into())
+    }
+
+    fn animate(disk: &Self::Model, frames: &mut Frames) -> Result<(), Error> {
+        let mut disk = disk.clone();
+        frames.push_with(|| disk.frame());
+        disk.defrag_with(|disk| frames.push_with(|| disk.frame()))?;
+        frames.finish_with(|| disk.frame());
+
+        Ok(())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_disk_map(rng, size.unwrap_or(INPUT_DIGITS)))
+    }
+}
+
+#[derive(Clone)]
+pub struct Disk {
+    blocks: Vec<Block>,
+    files: u64,
+}
+
+impl Disk {
+    fn parse(input: &str) -> Result<Self, Diagnostic> {
+        let mut blocks: Vec<Block> = Vec::new();
+
+        let mut file_id = 0;
+        let mut file = true;
+        for length in parse::digit_line(input)? {
+            let block: Block;
+            if file {
+                block = Block::File(file_id);
+                file_id += 1;
+            } else {
+                block = Block::Empty;
+            }
+
+            for _ in 0..length {
+                blocks.push(block.clone());
+            }
+
+            file = !file;
+        }
+
+        Ok(Self {
+            blocks,
+            files: file_id,
+        })
+    }
+
+    fn compact(&mut self) -> Result<(), Error> {
+        // An empty disk is already as compact as it gets
+        let Some(mut rear) = self.blocks.len().checked_sub(1) else {
+            return Ok(());
+        };
+        let mut front = 0;
+        loop {
+            if front == rear {
+                break;
+            }
+
+            let front_block = match self.blocks.get(front) {
+                Some(b) => b,
+                None => return Err(Error::new("Front block did not return block!".to_owned())),
+            };
+            if front_block != &Block::Empty {
+                front += 1;
+                continue;
+            }
+
+            let rear_block = match self.blocks.get(rear) {
+                Some(b) => b,
+                None => return Err(Error::new("Rear block did not return block!".to_owned())),
+            };
+            if rear_block == &Block::Empty {
+                rear -= 1;
+                continue;
+            }
+
+            self.blocks.swap(front, rear);
+        }
+
+        Ok(())
+    }
+
+    fn defrag(&mut self) -> Result<(), Error> {
+        self.defrag_with(|_| {})
+    }
+
+    /// Defragments the disk, calling `on_move` after each file is moved.
+    fn defrag_with<F>(&mut self, mut on_move: F) -> Result<(), Error>
+    where
+        F: FnMut(&Self),
+    {
+        for file_id in (0..self.files).rev() {
+            // A file of length zero takes up no blocks, so has nowhere to move
+            if let Some((file_start, file_len)) = self.find_file(file_id) {
+                let mut empty_space_start: Option<usize> = None;
+                for index in 0..file_start {
+                    let current_block = match self.blocks.get(index) {
+                        Some(b) => b,
+                        None => return Err(Error::new("Index did not return a block!".to_owned())),
+                    };
+
+                    match current_block {
+                        Block::Empty => {
+                            if let Some(space_start) = empty_space_start {
+                                if index - space_start + 1 == file_len {
+                                    self.swap_blocks(file_start, space_start, file_len)?;
+                                    on_move(self);
+                                    break;
+                                }
+                            } else if file_len == 1 {
+                                self.swap_blocks(file_start, index, file_len)?;
+                                on_move(self);
+                                break;
+                            } else {
+                                empty_space_start = Some(index);
+                                continue;
+                            }
+                        }
+                        Block::File(_) => {
+                            empty_space_start = None;
+                            continue;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_file(&self, file_id: u64) -> Option<(usize, usize)> {
+        let mut file_start: Option<usize> = None;
+        let mut file_len: usize = 0;
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            match block {
+                Block::Empty => {
+                    if file_len > 0 {
+                        break;
+                    }
+                }
+                Block::File(block_id) => {
+                    if *block_id == file_id {
+                        if file_start.is_none() {
+                            file_start = Some(index);
+                        }
+
+                        file_len += 1;
+                    }
+                }
+            }
+        }
+
+        file_start.map(|start| (start, file_len))
+    }
+
+    fn swap_blocks(&mut self, a: usize, b: usize, len: usize) -> Result<(), Error> {
+        let a_end = a + len - 1;
+        if a >= self.blocks.len() || a_end >= self.blocks.len() {
+            return Err(Error::new(format!(
+                "Invalid first block ({}, {})",
+                a, a_end
+            )));
+        }
+
+        let b_end = b + len - 1;
+        if b >= self.blocks.len() || b_end >= self.blocks.len() {
+            return Err(Error::new(format!(
+                "Invalid second block ({}, {})",
+                b, b_end
+            )));
+        }
+
+        if (a >= b && a <= b_end)
+            || (a_end >= b && a_end <= b_end)
+            || (b >= a && b <= a_end)
+            || (b_end >= a && b_end <= a_end)
+        {
+            return Err(Error::new(format!(
+                "Blocks overlap! a: ({}, {}) b: ({}, {})",
+                a, a_end, b, b_end
+            )));
+        }
+
+        for i in 0..=a_end - a {
+            self.blocks.swap(a + i, b + i);
+        }
+
+        Ok(())
+    }
+
+    /// The blocks wrapped into a roughly square grid, one cell per block,
+    /// with each file coloured by its ID.
+    fn frame(&self) -> Grid<Cell> {
+        let width = (self.blocks.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let height = self.blocks.len().div_ceil(width).max(1);
+        let mut cells = Grid::new(width, height, Cell::plain(' '));
+        for (index, block) in self.blocks.iter().enumerate() {
+            cells[(index % width, index / width)] = block.render();
+        }
+
+        cells
+    }
+
+    fn checksum(&self) -> u64 {
+        let mut checksum = 0;
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            if let Block::File(file_id) = block {
+                checksum += file_id * index as u64;
+            }
+        }
+
+        checksum
+    }
+}
+
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for block in &self.blocks {
+            write!(f, "{}", block)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum Block {
+    Empty,
+    File(u64),
+}
+
+impl Block {
+    fn render(&self) -> Cell {
+        match self {
+            Block::Empty => Cell::new(EMPTY_FILE, Style::new().dim()),
+            Block::File(file_id) => {
+                let color = Color::DISTINCT[*file_id as usize % Color::DISTINCT.len()];
+                let symbol = char::from_digit((file_id % 10) as u32, 10).unwrap_or('?');
+
+                Cell::new(symbol, Style::new().foreground(color))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Block::Empty => write!(f, "{}", EMPTY_FILE),
+            Block::File(file_id) => write!(f, "{}", file_id),
+        }
+    }
+}
+
+/// A disk map of `digits` digits alternating between file and free space
+/// lengths. Files always take at least one block, and the map starts and ends
+/// with a file.
+fn generate_disk_map(rng: &mut Rng, digits: usize) -> String {
+    // An odd number of digits so the last one is a file
+    let digits = digits.max(1) | 1;
+
+    let mut disk_map = String::new();
+    for i in 0..digits {
+        let length = if i % 2 == 0 {
+            rng.range(1..=9)
+        } else {
+            rng.range(0..=9)
+        };
+        disk_map += &length.to_string();
+    }
+    disk_map.push('\n');
+
+    disk_map
+}
+
+const INPUT_DIGITS: usize = 19999;
+const EMPTY_FILE: char = '.';
@@ -0,0 +1,159 @@
+use aoc_common::{
+    graph, parse, Answer, Cell, Color, Error, Grid, Memo, Point, Rng, Solution, Style,
+};
+use tracing::debug;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Model = Map;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Answer, Error> {
+        Ok(map.num_peaks().into())
+    }
+
+    fn part2(map: &Self::Model) -> Result<Answer, Error> {
+        Ok(map.num_paths().into())
+    }
+
+    fn render(map: &Self::Model) -> Result<Option<Grid<Cell>>, Error> {
+        Ok(Some(map.render()))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_map(rng, size.unwrap_or(INPUT_SIZE)))
+    }
+}
+
+pub struct Map {
+    elevations: Grid<u8>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let elevations = parse::digit_grid(input)?;
+
+        Ok(Self { elevations })
+    }
+
+    fn trail_heads(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.elevations
+            .find_all(|elevation| *elevation == TRAIL_BEGIN)
+    }
+
+    fn num_peaks(&self) -> usize {
+        let mut peaks = 0;
+
+        for trail_head in self.trail_heads() {
+            let reachable = graph::bfs(trail_head, |location| self.uphill(*location));
+            peaks += reachable
+                .keys()
+                .filter(|location| self.elevations[**location] == TRAIL_END)
+                .count();
+        }
+
+        peaks
+    }
+
+    fn num_paths(&self) -> usize {
+        // Trails from different heads share their upper sections
+        let mut memo = Memo::new();
+        let mut paths = 0;
+
+        for trail_head in self.trail_heads() {
+            paths += self.paths_from(&mut memo, trail_head);
+        }
+        debug!(stats = ?memo.stats(), "path memo");
+
+        paths
+    }
+
+    fn paths_from(
+        &self,
+        memo: &mut Memo<(usize, usize), usize>,
+        location: (usize, usize),
+    ) -> usize {
+        if self.elevations[location] == TRAIL_END {
+            return 1;
+        }
+
+        memo.get_or_insert_with(location, |memo| {
+            self.uphill(location)
+                .map(|next| self.paths_from(memo, next))
+                .sum()
+        })
+    }
+
+    /// The elevations shaded from dark valleys to bright peaks, with the
+    /// trail heads picked out.
+    fn render(&self) -> Grid<Cell> {
+        self.elevations.map(|elevation| {
+            let symbol = char::from(b'0' + elevation);
+            let style = match *elevation {
+                TRAIL_BEGIN => TRAIL_HEAD_STYLE,
+                TRAIL_END => PEAK_STYLE,
+                // Spread the heights over the lighter two thirds of the ramp
+                _ => Style::new().foreground(Color::grey(6 + elevation * 2)),
+            };
+
+            Cell::new(symbol, style)
+        })
+    }
+
+    /// The neighbours of `location` that are exactly one step higher.
+    fn uphill(&self, location: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let next_elevation = self.elevations[location] + 1;
+        self.elevations
+            .neighbours4(location)
+            .filter(move |neighbour| self.elevations[*neighbour] == next_elevation)
+    }
+}
+
+/// A `size` by `size` map of hills. Each peak slopes down one step at a time
+/// so there are trails to follow, and some elevations are scrambled so that
+/// not every slope is a complete trail.
+fn generate_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut elevations = Grid::new(size, size, TRAIL_BEGIN);
+
+    let reach = TRAIL_END as isize;
+    for _ in 0..(size * size / CELLS_PER_PEAK).max(1) {
+        let peak = Point::from((rng.below(size), rng.below(size)));
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                let location = Point::new(peak.x + dx, peak.y + dy);
+                let distance = peak.manhattan(location);
+                if let Some(elevation) = elevations.get_point_mut(location) {
+                    let height = TRAIL_END.saturating_sub(distance as u8);
+                    *elevation = (*elevation).max(height);
+                }
+            }
+        }
+    }
+
+    elevations
+        .map(|elevation| {
+            let elevation = if rng.chance(SCRAMBLE_CHANCE) {
+                rng.range(0..=TRAIL_END as u64) as u8
+            } else {
+                *elevation
+            };
+
+            char::from(b'0' + elevation)
+        })
+        .to_string()
+}
+
+const INPUT_SIZE: usize = 52;
+const CELLS_PER_PEAK: usize = 40;
+const SCRAMBLE_CHANCE: f64 = 0.1;
+
+const TRAIL_END: u8 = 9;
+const TRAIL_BEGIN: u8 = 0;
+
+const TRAIL_HEAD_STYLE: Style = Style::new().foreground(Color::BrightGreen).bold();
+const PEAK_STYLE: Style = Style::new().foreground(Color::BrightWhite).bold();
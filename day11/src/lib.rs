@@ -0,0 +1,184 @@
+use aoc_common::{
+    parse::{self, Span},
+    Answer, Diagnostic, Error, Memo, Rng, Solution,
+};
+use tracing::{debug, trace, Level};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Model = Vec<Stone>;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(get_stones(input)?)
+    }
+
+    fn part1(stones: &Self::Model) -> Result<Answer, Error> {
+        let mut stones = stones.clone();
+        trace_stones(0, &stones);
+        for i in 1..=PART1_BLINKS {
+            stones = blink_all(stones)?;
+            trace_stones(i, &stones);
+        }
+
+        Ok(stones.len().into())
+    }
+
+    fn part2(stones: &Self::Model) -> Result<Answer, Error> {
+        let mut total = 0;
+        let mut memo = Memo::new();
+
+        for stone in stones {
+            total += blink(&mut memo, stone.number, 1, PART2_BLINKS);
+        }
+        debug!(stats = ?memo.stats(), cached = memo.len(), "blink memo");
+
+        Ok(total.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(generate_stones(rng, size.unwrap_or(INPUT_STONES)))
+    }
+}
+
+fn blink_all(stones: Vec<Stone>) -> Result<Vec<Stone>, Error> {
+    let mut new_stones = Vec::new();
+    for stone in stones {
+        new_stones.append(&mut stone.blink()?);
+    }
+
+    Ok(new_stones)
+}
+
+fn get_stones(input: &str) -> Result<Vec<Stone>, Diagnostic> {
+    let mut stones = Vec::new();
+
+    for line in parse::lines(input) {
+        for number in line.split_whitespace() {
+            stones.push(Stone::parse(number)?);
+        }
+    }
+
+    Ok(stones)
+}
+
+fn trace_stones(blink_count: i32, stones: &Vec<Stone>) {
+    // Building the line is expensive, so skip it unless it will be shown
+    if !tracing::enabled!(Level::TRACE) {
+        return;
+    }
+
+    let mut output = format!("{}:", blink_count);
+    for stone in stones {
+        output = format!("{} {}", &output, stone.number);
+    }
+
+    trace!("{}", &output);
+}
+
+#[derive(Clone)]
+pub struct Stone {
+    number: usize,
+}
+
+impl Stone {
+    fn parse(number: Span) -> Result<Self, Diagnostic> {
+        Ok(Self {
+            number: number.parse()?,
+        })
+    }
+
+    fn blink(&self) -> Result<Vec<Self>, Error> {
+        if self.number == 0 {
+            Ok(vec![Self { number: 1 }])
+        } else if self.number.to_string().len().is_multiple_of(2) {
+            let mut first_number = self.number.to_string();
+            let second_number = first_number.split_off(first_number.len() / 2);
+            Ok(vec![
+                Self {
+                    number: str::parse::<usize>(&first_number)?,
+                },
+                Self {
+                    number: str::parse::<usize>(&second_number)?,
+                },
+            ])
+        } else {
+            Ok(vec![Self {
+                number: self.number * STONE_MULTIPLIER,
+            }])
+        }
+    }
+}
+
+fn blink(
+    memo: &mut Memo<(usize, usize), usize>,
+    number: usize,
+    current_iteration: usize,
+    max_iterations: usize,
+) -> usize {
+    if current_iteration == max_iterations {
+        return if num_digits(number).is_multiple_of(2) {
+            2
+        } else {
+            1
+        };
+    }
+
+    memo.get_or_insert_with((number, current_iteration), |memo| {
+        let next_iteration = current_iteration + 1;
+        if number == 0 {
+            return blink(memo, 1, next_iteration, max_iterations);
+        }
+
+        let num_digits = num_digits(number);
+        if num_digits.is_multiple_of(2) {
+            let divisor = TEN.pow(num_digits / 2);
+            blink(memo, number / divisor, next_iteration, max_iterations)
+                + blink(memo, number % divisor, next_iteration, max_iterations)
+        } else {
+            blink(
+                memo,
+                number * STONE_MULTIPLIER,
+                next_iteration,
+                max_iterations,
+            )
+        }
+    })
+}
+
+fn num_digits(number: usize) -> u32 {
+    let mut num_digits = 1;
+    let mut modulus = 10;
+    loop {
+        if number % modulus == number {
+            return num_digits;
+        }
+
+        modulus *= 10;
+        num_digits += 1;
+    }
+}
+
+/// A line of `stones` engraved numbers of one to seven digits, with the odd
+/// 0 thrown in.
+fn generate_stones(rng: &mut Rng, stones: usize) -> String {
+    let stones: Vec<String> = (0..stones.max(1))
+        .map(|_| {
+            if rng.chance(0.1) {
+                0
+            } else {
+                let digits = rng.range(1..=7) as u32;
+                rng.range(1..=TEN.pow(digits) as u64 - 1)
+            }
+        })
+        .map(|number| number.to_string())
+        .collect();
+
+    stones.join(" ") + "\n"
+}
+
+const INPUT_STONES: usize = 8;
+const TEN: usize = 10;
+const PART1_BLINKS: i32 = 25;
+const PART2_BLINKS: usize = 75;
+const STONE_MULTIPLIER: usize = 2024;
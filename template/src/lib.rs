@@ -0,0 +1,24 @@
+use aoc_common::{Answer, Error, Solution};
+use tracing::debug;
+
+pub struct Template;
+
+impl Solution for Template {
+    type Model = String;
+
+    fn parse(input: &str) -> Result<Self::Model, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Model) -> Result<Answer, Error> {
+        debug!(input);
+
+        Ok(0u64.into())
+    }
+
+    fn part2(input: &Self::Model) -> Result<Answer, Error> {
+        debug!(input);
+
+        Ok(0u64.into())
+    }
+}